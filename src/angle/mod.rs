@@ -2,9 +2,13 @@ use std::fmt::{Display, Formatter};
 use std::ops::{Add, Sub};
 use crate::scalar::Scalar;
 
-#[derive(Debug, Clone, Copy)]
+///With the `serde` feature angles keep their unit, e.g. `{"deg": 90.0}` or `{"rad": 1.57}`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Angle<S: Scalar> {
+    #[cfg_attr(feature = "serde", serde(rename = "rad"))]
     Radians(S),
+    #[cfg_attr(feature = "serde", serde(rename = "deg"))]
     Degrees(S),
 }
 
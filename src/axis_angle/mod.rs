@@ -1,7 +1,8 @@
 use crate::angle::Angle;
 use crate::scalar::Scalar;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AxisAngle<S: Scalar> {
     pub alpha: Angle<S>,
     pub beta: Angle<S>,
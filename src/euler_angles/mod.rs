@@ -3,7 +3,8 @@ use std::fmt::{Display, Formatter};
 use crate::angle::Angle;
 use crate::scalar::Scalar;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EulerAngles<S: Scalar> {
     pub roll: Angle<S>,
     pub pitch: Angle<S>,
@@ -0,0 +1,118 @@
+use crate::scalar::Scalar;
+use crate::vector::Vector;
+
+///A GLSL `genType`: a scalar or a vector that the shader functions below apply to component-wise.
+pub trait GenType: Copy {
+    type Scalar: Scalar;
+
+    fn map<F: Fn(Self::Scalar) -> Self::Scalar>(self, f: F) -> Self;
+    fn zip_map<F: Fn(Self::Scalar, Self::Scalar) -> Self::Scalar>(self, other: Self, f: F) -> Self;
+    fn dot(self, other: Self) -> Self::Scalar;
+}
+
+impl<S: Scalar> GenType for S {
+    type Scalar = S;
+
+    fn map<F: Fn(S) -> S>(self, f: F) -> Self {
+        f(self)
+    }
+
+    fn zip_map<F: Fn(S, S) -> S>(self, other: Self, f: F) -> Self {
+        f(self, other)
+    }
+
+    fn dot(self, other: Self) -> S {
+        self * other
+    }
+}
+
+impl<const L: usize, S: Scalar> GenType for Vector<L, S> {
+    type Scalar = S;
+
+    fn map<F: Fn(S) -> S>(self, f: F) -> Self {
+        Vector(self.0.map(f))
+    }
+
+    fn zip_map<F: Fn(S, S) -> S>(self, other: Self, f: F) -> Self {
+        let mut out = self;
+        for i in 0..L {
+            out.0[i] = f(self.0[i], other.0[i]);
+        }
+        out
+    }
+
+    fn dot(self, other: Self) -> S {
+        self.dot_product(other)
+    }
+}
+
+fn minimum<S: Scalar>(a: S, b: S) -> S {
+    if b < a { b } else { a }
+}
+
+fn maximum<S: Scalar>(a: S, b: S) -> S {
+    if b > a { b } else { a }
+}
+
+///Linear blend `x * (1 - a) + y * a`. `a` may be a scalar or a vector.
+pub fn mix<T: GenType>(x: T, y: T, a: impl Into<T>) -> T {
+    let a = a.into();
+    x.zip_map(a, |x, a| x * (T::Scalar::ONE - a))
+        .zip_map(y.zip_map(a, |y, a| y * a), |x, y| x + y)
+}
+
+///`0` where `x < edge`, `1` otherwise.
+pub fn step<T: GenType>(edge: impl Into<T>, x: T) -> T {
+    edge.into().zip_map(x, |e, x| if x < e { T::Scalar::ZERO } else { T::Scalar::ONE })
+}
+
+///Hermite interpolation between `0` at `edge0` and `1` at `edge1`.
+pub fn smoothstep<T: GenType>(edge0: impl Into<T>, edge1: impl Into<T>, x: T) -> T {
+    let (zero, one) = T::Scalar::zero_one();
+    let (two, three) = (T::Scalar::from_f32(2.0), T::Scalar::from_f32(3.0));
+    let edge0 = edge0.into();
+    let range = edge1.into().zip_map(edge0, |e1, e0| e1 - e0);
+    x.zip_map(edge0, |x, e0| x - e0)
+        .zip_map(range, |d, r| minimum(maximum(d / r, zero), one))
+        .map(|t| t * t * (three - two * t))
+}
+
+///`x - floor(x)`
+pub fn fract<T: GenType>(x: T) -> T {
+    x.map(|x| x - x.floored())
+}
+
+pub fn clamp<T: GenType>(x: T, min: impl Into<T>, max: impl Into<T>) -> T {
+    x.zip_map(min.into(), maximum).zip_map(max.into(), minimum)
+}
+
+///`-1`, `0` or `1` depending on the sign of each component.
+pub fn sign<T: GenType>(x: T) -> T {
+    x.map(|x| {
+        if x > T::Scalar::ZERO {
+            T::Scalar::ONE
+        } else if x < T::Scalar::ZERO {
+            T::Scalar::NEG_ONE
+        } else {
+            T::Scalar::ZERO
+        }
+    })
+}
+
+///GLSL `mod`: `x - y * floor(x / y)`, so the result takes the sign of `y`.
+pub fn r#mod<T: GenType>(x: T, y: impl Into<T>) -> T {
+    x.zip_map(y.into(), |x, y| x - y * (x / y).floored())
+}
+
+pub fn inversesqrt<T: GenType>(x: T) -> T {
+    x.map(|x| T::Scalar::ONE / x.square_root())
+}
+
+pub fn length<T: GenType>(x: T) -> T::Scalar {
+    x.dot(x).square_root()
+}
+
+///`n` if `nref` faces against the incident vector `i`, `-n` otherwise.
+pub fn faceforward<const L: usize, S: Scalar>(n: Vector<L, S>, i: Vector<L, S>, nref: Vector<L, S>) -> Vector<L, S> {
+    if nref.dot_product(i) < S::ZERO { n } else { -n }
+}
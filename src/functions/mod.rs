@@ -11,6 +11,10 @@ use crate::vector::{Vector, Vector2, Vector3, Vector4};
 use crate::vector::f32::VectorF32;
 use crate::vector::f64::VectorF64;
 
+pub mod glsl;
+
+pub use glsl::{clamp, faceforward, fract, inversesqrt, length, mix, r#mod, sign, smoothstep, step, GenType};
+
 pub fn atan2<S: Scalar>(a: S, b: S) -> S {
     a.inv_tangent2(b)
 }
@@ -85,6 +89,28 @@ pub fn mat_transpose<const M: usize, const N: usize, S: Scalar>(matrix: Matrix<M
     out
 }
 
+pub fn mat_mul<const M: usize, const N: usize, const P: usize, S: Scalar>(a: Matrix<M, N, S>, b: Matrix<N, P, S>) -> Matrix<M, P, S> {
+    let mut out: Matrix<M, P, S> = Matrix::ZERO;
+    for i in 0..M {
+        for j in 0..P {
+            for k in 0..N {
+                out.0[i][j] += a.0[i][k] * b.0[k][j];
+            }
+        }
+    }
+    out
+}
+
+pub fn mat_mul_vec<const M: usize, const N: usize, S: Scalar>(a: Matrix<M, N, S>, v: Vector<N, S>) -> Vector<M, S> {
+    let mut out: Vector<M, S> = Vector::ZERO;
+    for i in 0..M {
+        for j in 0..N {
+            out.0[i] += a.0[i][j] * v.0[j];
+        }
+    }
+    out
+}
+
 pub fn max<C: Ord>(a: C, b: C) -> C {
     std::cmp::max(a, b)
 }
@@ -97,28 +123,30 @@ pub fn mat2<S: Scalar>(a: S, b: S, c: S, d: S) -> Mat2<S> {
     Matrix([
                [a, b],
                [c, d]
-           ], 4)
+           ])
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn mat3<S: Scalar>(a: S, b: S, c: S, d: S, e: S, f: S, g: S, h: S, i: S) -> Mat3<S> {
     Matrix([
                [a, b, c],
                [d, e, f],
                [g, h, i]
-           ], 4)
+           ])
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn mat4<S: Scalar>(a: S, b: S, c: S, d: S, e: S, f: S, g: S, h: S, i: S, j: S, k: S, l: S, m: S, n: S, o: S, p: S) -> Mat4<S> {
     Matrix([
                [a, b, c, d],
                [e, f, g, h],
                [i, j, k, l],
                [m, n, o, p]
-           ], 4)
+           ])
 }
 
 pub fn mat_fill<const M: usize, const N: usize, S: Scalar>(fill: S) -> Matrix<M, N, S> {
-    Matrix([[fill; N]; M], 4)
+    Matrix([[fill; N]; M])
 }
 
 pub fn mat_identity_fill<const L: usize, S: Scalar>(fill: S) -> Matrix<L, L, S> {
@@ -144,20 +172,20 @@ pub fn scale<S: Scalar>(scale: Vector3<S>) -> Mat4<S> {
                [zero, scale.y(), zero, zero],
                [zero, zero, scale.z(), zero],
                [zero, zero, zero, one]
-           ], 4)
+           ])
 }
 
 pub fn look_at<S: Scalar>(eye: Vector3<S>, center: Vector3<S>, up: Vector3<S>) -> Mat4<S> {
     let f = (center - eye).normalize();
-    let s = f.cross_product(up);
+    let s = f.cross_product(up).normalize();
     let u = s.cross_product(f);
 
     Matrix([
                [s.x(), u.x(), -f.x(), S::ZERO],
-               [s.y(), u.y(), -f.z(), S::ZERO],
+               [s.y(), u.y(), -f.y(), S::ZERO],
                [s.z(), u.z(), -f.z(), S::ZERO],
                [-s.dot_product(eye), -u.dot_product(eye), f.dot_product(eye), S::ONE]
-           ], 4)
+           ])
 }
 
 pub fn perspective<S: Scalar>(aspect_ratio: S, fov: S, near: S, far: S) -> Mat4<S> {
@@ -175,7 +203,7 @@ pub fn perspective<S: Scalar>(aspect_ratio: S, fov: S, near: S, far: S) -> Mat4<
                [zero, b, zero, zero],
                [zero, zero, c, e],
                [zero, zero, d, zero]
-           ], 4)
+           ])
 }
 
 pub fn orthographic<S: Scalar>(left: S, right: S, bottom: S, top: S, near: S, far: S) -> Mat4<S> {
@@ -186,7 +214,7 @@ pub fn orthographic<S: Scalar>(left: S, right: S, bottom: S, top: S, near: S, fa
                [zero, two / (top - bottom), zero, -(top + bottom) / (top - bottom)],
                [zero, zero, -two / (far - near), -(far + near) / (far - near)],
                [zero, zero, zero, one]
-           ], 4)
+           ])
 }
 
 pub fn rotation_x<S: Scalar>(theta: S) -> Mat3<S> {
@@ -196,7 +224,7 @@ pub fn rotation_x<S: Scalar>(theta: S) -> Mat3<S> {
                [one, zero, zero],
                [zero, cos, -sin],
                [zero, sin, cos]
-           ], 4)
+           ])
 }
 
 pub fn rotation_y<S: Scalar>(theta: S) -> Mat3<S> {
@@ -206,7 +234,7 @@ pub fn rotation_y<S: Scalar>(theta: S) -> Mat3<S> {
                [cos, zero, sin],
                [zero, one, zero],
                [-sin, zero, cos],
-           ], 4)
+           ])
 }
 
 pub fn rotation_z<S: Scalar>(theta: S) -> Mat3<S> {
@@ -216,7 +244,7 @@ pub fn rotation_z<S: Scalar>(theta: S) -> Mat3<S> {
                [cos, -sin, zero],
                [sin, cos, zero],
                [zero, zero, one],
-           ], 4)
+           ])
 }
 
 pub fn mat_to_mat2<S: Scalar, M: MatrixConversion<S>>(matrix: M) -> Mat2<S> {
@@ -243,7 +271,7 @@ pub fn mat2_mul_mat2<S: Scalar>(a: Mat2<S>, b: Mat2<S>) -> Mat2<S> {
     Matrix([
                [ab11, ab12],
                [ab21, ab22]
-           ], 4)
+           ])
 }
 
 pub fn mat3_mul_mat3<S: Scalar>(a: Mat3<S>, b: Mat3<S>) -> Mat3<S> {
@@ -272,7 +300,7 @@ pub fn mat3_mul_mat3<S: Scalar>(a: Mat3<S>, b: Mat3<S>) -> Mat3<S> {
                [ab11, ab12, ab13],
                [ab21, ab22, ab23],
                [ab31, ab32, ab33]
-           ], 4)
+           ])
 }
 
 pub fn mat4_mul_mat4<S: Scalar>(a: Mat4<S>, b: Mat4<S>) -> Mat4<S> {
@@ -289,6 +317,29 @@ pub fn mat4_mul_mat4<S: Scalar>(a: Mat4<S>, b: Mat4<S>) -> Mat4<S> {
     out
 }
 
+pub fn mat4_transform_point<S: Scalar>(m: Mat4<S>, point: Vector3<S>) -> Vector3<S> {
+    let p = m * vec4(point.x(), point.y(), point.z(), S::ONE);
+    let w = p.w();
+    if w == S::ONE || w == S::ZERO {
+        vec3(p.x(), p.y(), p.z())
+    } else {
+        vec3(p.x() / w, p.y() / w, p.z() / w)
+    }
+}
+
+pub fn mat4_transform_vector<S: Scalar>(m: Mat4<S>, vector: Vector3<S>) -> Vector3<S> {
+    let v = m * vec4(vector.x(), vector.y(), vector.z(), S::ZERO);
+    vec3(v.x(), v.y(), v.z())
+}
+
+pub fn quat_rotate_vec3<S: Scalar>(q: Quaternion<S>, v: Vector3<S>) -> Vector3<S> {
+    //v' = v + 2w(u × v) + 2u × (u × v)
+    let two = S::from_f32(2.0);
+    let u = *q.xyz();
+    let t = u.cross_product(v) * two;
+    v + t * *q.w() + u.cross_product(t)
+}
+
 pub fn quat_mul_quat<S: Scalar>(a: Quaternion<S>, b: Quaternion<S>) -> Quaternion<S> {
     let (a_w, a_x, a_y, a_z) = a.w_xyz();
     let (b_w, b_x, b_y, b_z) = b.w_xyz();
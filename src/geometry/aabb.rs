@@ -0,0 +1,132 @@
+use crate::geometry::{linear_part, Transform};
+use crate::matrix::mat3::Mat3;
+use crate::matrix::mat4::Mat4;
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector3};
+
+pub type Aabb2<S> = Aabb<2, S>;
+pub type Aabb3<S> = Aabb<3, S>;
+
+///Axis-aligned bounding box, with `min <= max` component-wise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb<const L: usize, S: Scalar> {
+    pub min: Vector<L, S>,
+    pub max: Vector<L, S>,
+}
+
+impl<const L: usize, S: Scalar> Aabb<L, S> {
+    ///The box spanned by two opposite corners, in any order.
+    pub fn new(a: Vector<L, S>, b: Vector<L, S>) -> Self {
+        Self { min: a.min(b), max: a.max(b) }
+    }
+
+    pub fn from_center_half_extents(center: Vector<L, S>, half_extents: Vector<L, S>) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    ///The smallest box containing all `points`, `None` if there are none.
+    pub fn from_points(points: &[Vector<L, S>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        Some(rest.iter().fold(Self { min: *first, max: *first }, |aabb, p| aabb.including(*p)))
+    }
+
+    pub fn center(&self) -> Vector<L, S> {
+        (self.min + self.max) / S::from_f32(2.0)
+    }
+
+    pub fn size(&self) -> Vector<L, S> {
+        self.max - self.min
+    }
+
+    pub fn half_extents(&self) -> Vector<L, S> {
+        self.size() / S::from_f32(2.0)
+    }
+
+    ///Boundary points are inside.
+    pub fn contains(&self, point: Vector<L, S>) -> bool {
+        (0..L).all(|i| self.min.0[i] <= point.0[i] && point.0[i] <= self.max.0[i])
+    }
+
+    pub fn contains_aabb(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    ///Whether the boxes overlap or touch. See [`aabb_aabb`](crate::geometry::overlap::aabb_aabb) for the penetration.
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..L).all(|i| self.min.0[i] <= other.max.0[i] && other.min.0[i] <= self.max.0[i])
+    }
+
+    ///The point of the box closest to `point`, which is `point` itself if it is inside.
+    pub fn closest_point(&self, point: Vector<L, S>) -> Vector<L, S> {
+        point.max(self.min).min(self.max)
+    }
+
+    ///Distance from `point` to the box, zero inside it.
+    pub fn distance(&self, point: Vector<L, S>) -> S {
+        self.closest_point(point).distance(point)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self { min: self.min.min(other.min), max: self.max.max(other.max) }
+    }
+
+    ///The smallest box containing both `self` and `point`.
+    pub fn including(&self, point: Vector<L, S>) -> Self {
+        Self { min: self.min.min(point), max: self.max.max(point) }
+    }
+
+    ///Grows the box by `margin` on every side.
+    pub fn expanded(&self, margin: S) -> Self {
+        let margin = Vector::from(margin);
+        Self { min: self.min - margin, max: self.max + margin }
+    }
+}
+
+///Arvo's method: the center is transformed and the half extents are mapped through the absolute linear part.
+fn transform_box<const L: usize, S: Scalar>(aabb: &Aabb<L, S>, linear: &Matrix<L, L, S>, center: Vector<L, S>) -> Aabb<L, S> {
+    let half = aabb.half_extents();
+    let mut extents: Vector<L, S> = Vector::ZERO;
+    for i in 0..L {
+        for j in 0..L {
+            extents.0[i] += linear.0[i][j].absolute() * half.0[j];
+        }
+    }
+    Aabb::from_center_half_extents(center, extents)
+}
+
+impl<S: Scalar> Aabb2<S> {
+    ///Applies a 2D affine transform in homogeneous coordinates, translation in the last column.
+    pub fn transformed(&self, m: &Mat3<S>) -> Self {
+        let linear = Matrix([[m.0[0][0], m.0[0][1]], [m.0[1][0], m.0[1][1]]]);
+        let c = self.center();
+        let center = linear * c + Vector([m.0[0][2], m.0[1][2]]);
+        transform_box(self, &linear, center)
+    }
+}
+
+impl<S: Scalar> Aabb3<S> {
+    ///The eight corners, the `i`th taking `max` on the axes whose bit is set in `i`.
+    pub fn corners(&self) -> [Vector3<S>; 8] {
+        std::array::from_fn(|i| {
+            let mut corner = self.min;
+            for axis in 0..3 {
+                if i & (1 << axis) != 0 {
+                    corner.0[axis] = self.max.0[axis];
+                }
+            }
+            corner
+        })
+    }
+}
+
+impl<S: Scalar> Transform<S> for Aabb3<S> {
+    fn transformed(&self, m: &Mat4<S>) -> Self {
+        transform_box(self, &linear_part(m), m.transform_point(self.center()))
+    }
+
+    fn rotated(&self, q: &Quaternion<S>) -> Self {
+        transform_box(self, &q.rotation_matrix(), q.rotate(self.center()))
+    }
+}
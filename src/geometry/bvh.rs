@@ -0,0 +1,364 @@
+use crate::geometry::raycast::ray_aabb;
+use crate::geometry::{Aabb3, Ray, RayHit};
+use crate::scalar::Scalar;
+
+///Bins per axis tried by the surface area heuristic when splitting a node.
+const SAH_BINS: usize = 12;
+
+///Bounding volume hierarchy over items identified by index, each with one leaf. Built top-down with the binned
+///surface area heuristic, and kept up to date by [`insert`](Bvh::insert), [`remove`](Bvh::remove) and refitting.
+#[derive(Debug, Clone)]
+pub struct Bvh<S: Scalar> {
+    nodes: Vec<Node<S>>,
+    root: Option<usize>,
+    ///The leaf of each item, `None` once removed.
+    leaves: Vec<Option<usize>>,
+    free: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Node<S: Scalar> {
+    bounds: Aabb3<S>,
+    parent: Option<usize>,
+    kind: NodeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NodeKind {
+    Leaf(usize),
+    Branch(usize, usize),
+}
+
+impl<S: Scalar> Default for Bvh<S> {
+    fn default() -> Self {
+        Self { nodes: Vec::new(), root: None, leaves: Vec::new(), free: Vec::new() }
+    }
+}
+
+impl<S: Scalar> Bvh<S> {
+    ///Item `i` has the bounds `bounds[i]`.
+    pub fn new(bounds: &[Aabb3<S>]) -> Self {
+        let mut bvh = Self { leaves: vec![None; bounds.len()], ..Self::default() };
+        let mut items: Vec<usize> = (0..bounds.len()).collect();
+        if !items.is_empty() {
+            bvh.root = Some(bvh.build(bounds, &mut items, None));
+        }
+        bvh
+    }
+
+    fn build(&mut self, bounds: &[Aabb3<S>], items: &mut [usize], parent: Option<usize>) -> usize {
+        let node = self.nodes.len();
+        let enclosing = items.iter().skip(1).fold(bounds[items[0]], |b, i| b.union(&bounds[*i]));
+        if let [item] = *items {
+            self.leaves[item] = Some(node);
+            self.nodes.push(Node { bounds: enclosing, parent, kind: NodeKind::Leaf(item) });
+            return node;
+        }
+        self.nodes.push(Node { bounds: enclosing, parent, kind: NodeKind::Leaf(usize::MAX) });
+
+        let split = sah_split(bounds, items);
+        let (left, right) = items.split_at_mut(split);
+        let left = self.build(bounds, left, Some(node));
+        let right = self.build(bounds, right, Some(node));
+        self.nodes[node].kind = NodeKind::Branch(left, right);
+        node
+    }
+
+    ///The number of items, not counting removed ones.
+    pub fn len(&self) -> usize {
+        self.leaves.iter().filter(|leaf| leaf.is_some()).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    ///The bounds of everything in the tree.
+    pub fn bounds(&self) -> Option<Aabb3<S>> {
+        self.root.map(|root| self.nodes[root].bounds)
+    }
+
+    ///The bounds of `item`, `None` if it was removed or never existed.
+    pub fn item_bounds(&self, item: usize) -> Option<Aabb3<S>> {
+        let leaf = (*self.leaves.get(item)?)?;
+        Some(self.nodes[leaf].bounds)
+    }
+
+    ///Adds an item and returns its index, which is never reused. The new leaf goes next to the node whose
+    ///bounds grow the least by including it.
+    pub fn insert(&mut self, bounds: Aabb3<S>) -> usize {
+        let item = self.leaves.len();
+        let leaf = self.allocate(Node { bounds, parent: None, kind: NodeKind::Leaf(item) });
+        self.leaves.push(Some(leaf));
+
+        let Some(mut sibling) = self.root else {
+            self.root = Some(leaf);
+            return item;
+        };
+        while let NodeKind::Branch(left, right) = self.nodes[sibling].kind {
+            let merged = area(&self.nodes[sibling].bounds.union(&bounds));
+            //Pushing the leaf further down enlarges every node on the way
+            let inherited = merged - area(&self.nodes[sibling].bounds);
+            let descend = |child: usize| {
+                let grown = area(&self.nodes[child].bounds.union(&bounds));
+                let growth = match self.nodes[child].kind {
+                    NodeKind::Leaf(_) => grown,
+                    NodeKind::Branch(..) => grown - area(&self.nodes[child].bounds),
+                };
+                growth + inherited
+            };
+            let (cost_left, cost_right) = (descend(left), descend(right));
+            if merged <= cost_left && merged <= cost_right {
+                break;
+            }
+            sibling = if cost_left <= cost_right { left } else { right };
+        }
+
+        let parent = self.nodes[sibling].parent;
+        let branch = self.allocate(Node {
+            bounds: self.nodes[sibling].bounds.union(&bounds),
+            parent,
+            kind: NodeKind::Branch(sibling, leaf),
+        });
+        self.nodes[sibling].parent = Some(branch);
+        self.nodes[leaf].parent = Some(branch);
+        self.replace_child(parent, sibling, branch);
+        self.refit_ancestors(branch);
+        item
+    }
+
+    ///Removes `item`, returning whether it was in the tree. Its sibling takes the place of their parent.
+    pub fn remove(&mut self, item: usize) -> bool {
+        let Some(leaf) = self.leaves.get_mut(item).and_then(Option::take) else {
+            return false;
+        };
+        self.free.push(leaf);
+        let Some(parent) = self.nodes[leaf].parent else {
+            self.root = None;
+            return true;
+        };
+        let NodeKind::Branch(left, right) = self.nodes[parent].kind else {
+            unreachable!("leaves only have branches as parents")
+        };
+        let sibling = if left == leaf { right } else { left };
+        let grandparent = self.nodes[parent].parent;
+        self.nodes[sibling].parent = grandparent;
+        self.replace_child(grandparent, parent, sibling);
+        self.free.push(parent);
+        if let Some(grandparent) = grandparent {
+            self.refit_ancestors(grandparent);
+        }
+        true
+    }
+
+    ///Moves `item` to new bounds and refits the nodes above it. Returns whether the item is in the tree.
+    pub fn update(&mut self, item: usize, bounds: Aabb3<S>) -> bool {
+        let Some(Some(leaf)) = self.leaves.get(item).copied() else {
+            return false;
+        };
+        self.nodes[leaf].bounds = bounds;
+        if let Some(parent) = self.nodes[leaf].parent {
+            self.refit_ancestors(parent);
+        }
+        true
+    }
+
+    ///Takes new bounds for every item from `bounds` and refits the whole tree bottom-up, keeping its structure.
+    ///The tree gets slower to query as items drift from where it was built; rebuild it with [`new`](Bvh::new) then.
+    pub fn refit<F: FnMut(usize) -> Aabb3<S>>(&mut self, mut bounds: F) {
+        if let Some(root) = self.root {
+            self.refit_node(root, &mut bounds);
+        }
+    }
+
+    fn refit_node<F: FnMut(usize) -> Aabb3<S>>(&mut self, node: usize, bounds: &mut F) -> Aabb3<S> {
+        let refitted = match self.nodes[node].kind {
+            NodeKind::Leaf(item) => bounds(item),
+            NodeKind::Branch(left, right) => self.refit_node(left, bounds).union(&self.refit_node(right, bounds)),
+        };
+        self.nodes[node].bounds = refitted;
+        refitted
+    }
+
+    fn refit_ancestors(&mut self, mut node: usize) {
+        loop {
+            if let NodeKind::Branch(left, right) = self.nodes[node].kind {
+                self.nodes[node].bounds = self.nodes[left].bounds.union(&self.nodes[right].bounds);
+            }
+            match self.nodes[node].parent {
+                Some(parent) => node = parent,
+                None => break,
+            }
+        }
+    }
+
+    fn allocate(&mut self, node: Node<S>) -> usize {
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn replace_child(&mut self, parent: Option<usize>, old: usize, new: usize) {
+        match parent {
+            None => self.root = Some(new),
+            Some(parent) => {
+                if let NodeKind::Branch(left, right) = &mut self.nodes[parent].kind {
+                    if *left == old {
+                        *left = new;
+                    } else if *right == old {
+                        *right = new;
+                    }
+                }
+            }
+        }
+    }
+
+    ///The nearest hit within `max_distance`, with the item it belongs to. `hit` casts the ray against an item
+    ///and is only called for items whose bounds the ray reaches before the nearest hit found so far.
+    pub fn raycast<F>(&self, ray: &Ray<S>, max_distance: S, mut hit: F) -> Option<(usize, RayHit<S>)>
+    where
+        F: FnMut(usize) -> Option<RayHit<S>>,
+    {
+        let mut nearest: Option<(usize, RayHit<S>)> = None;
+        let mut limit = max_distance;
+        let mut stack: Vec<(usize, Option<S>)> = self.root.map(|root| (root, entry_distance(ray, &self.nodes[root].bounds))).into_iter().collect();
+        while let Some((node, entry)) = stack.pop() {
+            if entry.is_none_or(|t| t > limit) {
+                continue;
+            }
+            match self.nodes[node].kind {
+                NodeKind::Leaf(item) => {
+                    if let Some(h) = hit(item).filter(|h| h.distance <= limit) {
+                        limit = h.distance;
+                        nearest = Some((item, h));
+                    }
+                }
+                NodeKind::Branch(left, right) => {
+                    let near = (left, entry_distance(ray, &self.nodes[left].bounds));
+                    let far = (right, entry_distance(ray, &self.nodes[right].bounds));
+                    //Visit the nearer child first so the limit shrinks sooner
+                    match (near.1, far.1) {
+                        (Some(a), Some(b)) if b < a => stack.extend([near, far]),
+                        _ => stack.extend([far, near]),
+                    }
+                }
+            }
+        }
+        nearest
+    }
+
+    ///Any hit within `max_distance`, not necessarily the nearest, e.g. for shadow rays. Stops at the first one.
+    pub fn raycast_any<F>(&self, ray: &Ray<S>, max_distance: S, mut hit: F) -> Option<(usize, RayHit<S>)>
+    where
+        F: FnMut(usize) -> Option<RayHit<S>>,
+    {
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(node) = stack.pop() {
+            if entry_distance(ray, &self.nodes[node].bounds).is_none_or(|t| t > max_distance) {
+                continue;
+            }
+            match self.nodes[node].kind {
+                NodeKind::Leaf(item) => {
+                    if let Some(h) = hit(item).filter(|h| h.distance <= max_distance) {
+                        return Some((item, h));
+                    }
+                }
+                NodeKind::Branch(left, right) => stack.extend([right, left]),
+            }
+        }
+        None
+    }
+
+    ///Items whose bounds intersect `aabb`, appended to `out` in no particular order.
+    pub fn query_aabb(&self, aabb: &Aabb3<S>, out: &mut Vec<usize>) {
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(node) = stack.pop() {
+            if !self.nodes[node].bounds.intersects(aabb) {
+                continue;
+            }
+            match self.nodes[node].kind {
+                NodeKind::Leaf(item) => out.push(item),
+                NodeKind::Branch(left, right) => stack.extend([right, left]),
+            }
+        }
+    }
+}
+
+///Where the ray enters the box, zero if it starts inside.
+fn entry_distance<S: Scalar>(ray: &Ray<S>, aabb: &Aabb3<S>) -> Option<S> {
+    if aabb.contains(ray.origin) {
+        return Some(S::ZERO);
+    }
+    ray_aabb(ray, aabb).map(|hit| hit.distance)
+}
+
+fn area<S: Scalar>(aabb: &Aabb3<S>) -> S {
+    let [x, y, z] = aabb.size().0;
+    S::from_f32(2.0) * (x * y + y * z + z * x)
+}
+
+///Reorders `items` so that those before the returned index go left, choosing the cheapest of the bin
+///boundaries along the axis where the centroids spread the most. Falls back to halving when they coincide.
+fn sah_split<S: Scalar>(bounds: &[Aabb3<S>], items: &mut [usize]) -> usize {
+    let centroid = |i: usize| bounds[i].center();
+    let (mut low, mut high) = (centroid(items[0]), centroid(items[0]));
+    for i in items.iter() {
+        low = low.min(centroid(*i));
+        high = high.max(centroid(*i));
+    }
+    let spread = high - low;
+    let axis = (0..3).fold(0, |best, i| if spread.0[i] > spread.0[best] { i } else { best });
+    if spread.0[axis] <= S::ZERO {
+        return items.len() / 2;
+    }
+
+    let width = spread.0[axis] / S::from_f32(SAH_BINS as f32);
+    let bin = |i: usize| {
+        let offset = centroid(i).0[axis] - low.0[axis];
+        (1..SAH_BINS).take_while(|k| offset >= width * S::from_f32(*k as f32)).count()
+    };
+    let mut counts = [0usize; SAH_BINS];
+    let mut boxes: [Option<Aabb3<S>>; SAH_BINS] = [None; SAH_BINS];
+    for i in items.iter() {
+        let b = bin(*i);
+        counts[b] += 1;
+        boxes[b] = Some(boxes[b].map_or(bounds[*i], |existing| existing.union(&bounds[*i])));
+    }
+
+    //Cost of splitting after each bin: the area of each side weighted by its item count
+    let sweep = |order: &mut dyn Iterator<Item = usize>| {
+        let mut out = [S::ZERO; SAH_BINS];
+        let (mut count, mut enclosing): (usize, Option<Aabb3<S>>) = (0, None);
+        for b in order {
+            count += counts[b];
+            if let Some(bin_box) = boxes[b] {
+                enclosing = Some(enclosing.map_or(bin_box, |e| e.union(&bin_box)));
+            }
+            out[b] = enclosing.map_or(S::ZERO, |e| area(&e)) * S::from_f32(count as f32);
+        }
+        out
+    };
+    let left = sweep(&mut (0..SAH_BINS));
+    let right = sweep(&mut (0..SAH_BINS).rev());
+    let best = (0..SAH_BINS - 1).fold(0, |best, b| if left[b] + right[b + 1] < left[best] + right[best + 1] { b } else { best });
+
+    let mut split = 0;
+    for k in 0..items.len() {
+        if bin(items[k]) <= best {
+            items.swap(k, split);
+            split += 1;
+        }
+    }
+    if split == 0 || split == items.len() {
+        items.len() / 2
+    } else {
+        split
+    }
+}
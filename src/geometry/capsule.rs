@@ -0,0 +1,50 @@
+use crate::geometry::{max_scale, Segment, Transform};
+use crate::matrix::mat4::Mat4;
+use crate::quaternion::Quaternion;
+use crate::scalar::Scalar;
+use crate::vector::Vector3;
+
+///All points within `radius` of `segment`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Capsule<S: Scalar> {
+    pub segment: Segment<S>,
+    pub radius: S,
+}
+
+impl<S: Scalar> Capsule<S> {
+    pub fn new(a: Vector3<S>, b: Vector3<S>, radius: S) -> Self {
+        Self { segment: Segment::new(a, b), radius }
+    }
+
+    pub fn contains(&self, point: Vector3<S>) -> bool {
+        self.segment.closest_point(point).distance_squared(point) <= self.radius * self.radius
+    }
+
+    ///The point of the capsule closest to `point`, which is `point` itself if it is inside.
+    pub fn closest_point(&self, point: Vector3<S>) -> Vector3<S> {
+        let axis = self.segment.closest_point(point);
+        let offset = point - axis;
+        let distance = offset.magnitude();
+        if distance <= self.radius {
+            point
+        } else {
+            axis + offset * (self.radius / distance)
+        }
+    }
+
+    ///Distance from `point` to the capsule, zero inside it.
+    pub fn distance(&self, point: Vector3<S>) -> S {
+        let d = self.segment.distance(point) - self.radius;
+        if d > S::ZERO { d } else { S::ZERO }
+    }
+}
+
+impl<S: Scalar> Transform<S> for Capsule<S> {
+    fn transformed(&self, m: &Mat4<S>) -> Self {
+        Self { segment: self.segment.transformed(m), radius: self.radius * max_scale(m) }
+    }
+
+    fn rotated(&self, q: &Quaternion<S>) -> Self {
+        Self { segment: self.segment.rotated(q), radius: self.radius }
+    }
+}
@@ -0,0 +1,242 @@
+use crate::functions::{rotation_x, rotation_y, rotation_z};
+use crate::geometry::{tolerance, Obb, Sphere};
+use crate::matrix::mat3::Mat3;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector3};
+
+///Angles tried around each axis by the minimum-volume search, before the best one is narrowed down.
+const REFINE_SAMPLES: usize = 18;
+const REFINE_NARROWING_STEPS: usize = 24;
+const REFINE_MAX_PASSES: usize = 8;
+
+///Box aligned with the principal axes of the point cloud, the eigenvectors of its covariance matrix.
+///Returns `None` for an empty slice.
+pub fn obb_pca<S: Scalar>(points: &[Vector3<S>]) -> Option<Obb<S>> {
+    if points.is_empty() {
+        return None;
+    }
+    let n = S::from_f32(points.len() as f32);
+    let mean = points.iter().fold(Vector3::ZERO, |sum, p| sum + *p) / n;
+
+    let mut covariance: Mat3<S> = Matrix::ZERO;
+    for p in points {
+        let d = *p - mean;
+        for i in 0..3 {
+            for j in 0..3 {
+                covariance.0[i][j] += d.0[i] * d.0[j] / n;
+            }
+        }
+    }
+
+    let mut rotation = covariance.symmetric_eigen().vectors;
+    //Keep a right-handed frame so the rotation converts to a quaternion
+    let axes = [0, 1, 2].map(|j| Vector([rotation.0[0][j], rotation.0[1][j], rotation.0[2][j]]));
+    if axes[0].cross_product(axes[1]).dot_product(axes[2]) < S::ZERO {
+        for i in 0..3 {
+            rotation.0[i][2] = -rotation.0[i][2];
+        }
+    }
+    Some(fit_with_rotation(points, rotation))
+}
+
+///Starts from [`obb_pca`] and rotates the box around each of its axes in turn while that shrinks its volume.
+///A heuristic: the result is never larger than the PCA box and usually close to the optimum.
+pub fn obb_min_volume<S: Scalar>(points: &[Vector3<S>]) -> Option<Obb<S>> {
+    let mut best = obb_pca(points)?;
+    let mut volume = best.volume();
+
+    for _ in 0..REFINE_MAX_PASSES {
+        let mut improved = false;
+        for axis in 0..3 {
+            let rotated = |theta: S| {
+                let turn = match axis {
+                    0 => rotation_x(theta),
+                    1 => rotation_y(theta),
+                    _ => rotation_z(theta),
+                };
+                fit_with_rotation(points, best.rotation * turn)
+            };
+
+            let quarter = S::PI / S::from_f32(2.0);
+            let step = quarter / S::from_f32(REFINE_SAMPLES as f32);
+            let mut candidate = (S::ZERO, volume);
+            for k in 1..REFINE_SAMPLES {
+                let theta = step * S::from_f32(k as f32);
+                let v = rotated(theta).volume();
+                if v < candidate.1 {
+                    candidate = (theta, v);
+                }
+            }
+
+            //Golden section search around the best sample
+            let ratio = S::from_f32(0.618_034);
+            let (mut lo, mut hi) = (candidate.0 - step, candidate.0 + step);
+            for _ in 0..REFINE_NARROWING_STEPS {
+                let a = hi - (hi - lo) * ratio;
+                let b = lo + (hi - lo) * ratio;
+                if rotated(a).volume() < rotated(b).volume() {
+                    hi = b;
+                } else {
+                    lo = a;
+                }
+            }
+            let theta = (lo + hi) / S::from_f32(2.0);
+            let v = rotated(theta).volume();
+            if v < candidate.1 {
+                candidate = (theta, v);
+            }
+
+            if candidate.1 < volume {
+                best = rotated(candidate.0);
+                volume = candidate.1;
+                improved = true;
+            }
+        }
+        if !improved {
+            break;
+        }
+    }
+    Some(best)
+}
+
+///The smallest box with the given orientation containing all `points`.
+pub(crate) fn fit_with_rotation<S: Scalar>(points: &[Vector3<S>], rotation: Mat3<S>) -> Obb<S> {
+    let axes = [0, 1, 2].map(|j| Vector([rotation.0[0][j], rotation.0[1][j], rotation.0[2][j]]));
+    let mut min = Vector([S::INFINITY; 3]);
+    let mut max = Vector([-S::INFINITY; 3]);
+    for p in points {
+        let local = Vector(axes.map(|a| a.dot_product(*p)));
+        min = min.min(local);
+        max = max.max(local);
+    }
+    let center = rotation * ((min + max) / S::from_f32(2.0));
+    Obb::new(center, (max - min) / S::from_f32(2.0), rotation)
+}
+
+///Ritter's approximate bounding sphere: a sphere around two distant points, grown to cover the rest.
+///Usually within a few percent of the minimal sphere. Returns `None` for an empty slice.
+pub fn sphere_ritter<S: Scalar>(points: &[Vector3<S>]) -> Option<Sphere<S>> {
+    let first = *points.first()?;
+    let farthest = |from: Vector3<S>| {
+        points.iter().copied().fold(from, |best, p| if from.distance_squared(p) > from.distance_squared(best) { p } else { best })
+    };
+    let y = farthest(first);
+    let z = farthest(y);
+
+    let mut sphere = Sphere::new(y.lerp(z, S::from_f32(0.5)), y.distance(z) / S::from_f32(2.0));
+    for p in points {
+        let d = sphere.center.distance(*p);
+        if d > sphere.radius {
+            let radius = (sphere.radius + d) / S::from_f32(2.0);
+            sphere.center = sphere.center + (*p - sphere.center) * ((radius - sphere.radius) / d);
+            sphere.radius = radius;
+        }
+    }
+    Some(sphere)
+}
+
+///Welzl's minimal bounding sphere, in its iterative form over a deterministically shuffled copy of the points.
+///Returns `None` for an empty slice.
+pub fn sphere_welzl<S: Scalar>(points: &[Vector3<S>]) -> Option<Sphere<S>> {
+    let mut points = points.to_vec();
+    shuffle(&mut points);
+    let p = &points;
+
+    let mut sphere = Sphere::new(*p.first()?, S::ZERO);
+    for i in 0..p.len() {
+        if covers(&sphere, p[i]) {
+            continue;
+        }
+        sphere = Sphere::new(p[i], S::ZERO);
+        for j in 0..i {
+            if covers(&sphere, p[j]) {
+                continue;
+            }
+            sphere = sphere_through2(p[i], p[j]);
+            for k in 0..j {
+                if covers(&sphere, p[k]) {
+                    continue;
+                }
+                sphere = sphere_through3(p[i], p[j], p[k]);
+                for l in 0..k {
+                    if !covers(&sphere, p[l]) {
+                        sphere = sphere_through4(p[i], p[j], p[k], p[l]);
+                    }
+                }
+            }
+        }
+    }
+    Some(sphere)
+}
+
+///Containment with a tolerance relative to the radius, so that points on the boundary do not restart the search.
+///A sphere of zero radius only covers its center.
+fn covers<S: Scalar>(sphere: &Sphere<S>, point: Vector3<S>) -> bool {
+    let slack = tolerance::<S>() * sphere.radius;
+    sphere.center.distance(point) <= sphere.radius + slack
+}
+
+fn sphere_through2<S: Scalar>(a: Vector3<S>, b: Vector3<S>) -> Sphere<S> {
+    Sphere::new(a.lerp(b, S::from_f32(0.5)), a.distance(b) / S::from_f32(2.0))
+}
+
+///The smallest sphere with all three points on its surface, centered on their circumcircle.
+fn sphere_through3<S: Scalar>(a: Vector3<S>, b: Vector3<S>, c: Vector3<S>) -> Sphere<S> {
+    let (ab, ac) = (b - a, c - a);
+    let normal = ab.cross_product(ac);
+    let denominator = S::from_f32(2.0) * normal.magnitude_squared();
+    if denominator <= S::EPSILON * ab.magnitude_squared() * ac.magnitude_squared() {
+        //Collinear: the two farthest apart span the sphere
+        return [sphere_through2(a, b), sphere_through2(a, c), sphere_through2(b, c)]
+            .into_iter()
+            .fold(sphere_through2(a, b), |best, s| if s.radius > best.radius { s } else { best });
+    }
+    let offset = (ac * ab.magnitude_squared() - ab * ac.magnitude_squared()).cross_product(normal) / denominator;
+    Sphere::new(a + offset, offset.magnitude())
+}
+
+///The sphere with all four points on its surface.
+fn sphere_through4<S: Scalar>(a: Vector3<S>, b: Vector3<S>, c: Vector3<S>, d: Vector3<S>) -> Sphere<S> {
+    let two = S::from_f32(2.0);
+    let rows = [b - a, c - a, d - a];
+    let m = Matrix(rows.map(|r| (r * two).0));
+    let rhs = Vector(rows.map(|r| r.magnitude_squared()));
+    match m.solve(rhs) {
+        Some(offset) => Sphere::new(a + offset, offset.magnitude()),
+        //Coplanar: the largest of the circumscribed spheres of the faces covers all four
+        None => [sphere_through3(a, b, c), sphere_through3(a, b, d), sphere_through3(a, c, d), sphere_through3(b, c, d)]
+            .into_iter()
+            .fold(sphere_through3(a, b, c), |best, s| if s.radius > best.radius { s } else { best }),
+    }
+}
+
+///Fisher-Yates with a fixed linear congruential generator, so results are reproducible.
+fn shuffle<T>(items: &mut [T]) {
+    let mut state: u64 = 0x853c_49e6_748f_ea9b;
+    for i in (1..items.len()).rev() {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let j = ((state >> 33) % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+}
+
+impl<S: Scalar> Obb<S> {
+    pub fn from_points_pca(points: &[Vector3<S>]) -> Option<Self> {
+        obb_pca(points)
+    }
+
+    pub fn from_points_min_volume(points: &[Vector3<S>]) -> Option<Self> {
+        obb_min_volume(points)
+    }
+}
+
+impl<S: Scalar> Sphere<S> {
+    pub fn from_points_ritter(points: &[Vector3<S>]) -> Option<Self> {
+        sphere_ritter(points)
+    }
+
+    pub fn from_points_welzl(points: &[Vector3<S>]) -> Option<Self> {
+        sphere_welzl(points)
+    }
+}
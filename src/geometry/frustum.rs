@@ -0,0 +1,102 @@
+use crate::geometry::{Aabb3, Plane, Sphere};
+use crate::matrix::mat4::Mat4;
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector3};
+
+///Where a shape lies relative to a [`Frustum`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Containment {
+    Inside,
+    Outside,
+    Intersecting,
+}
+
+///A view volume bounded by six planes whose normals point inwards, in the order left, right, bottom, top, near, far.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum<S: Scalar> {
+    pub planes: [Plane<S>; 6],
+}
+
+impl<S: Scalar> Frustum<S> {
+    ///Extracts the planes of a view-projection matrix mapping points to clip space as `m * p`, like
+    ///[`Mat4::transform_point`] and [`Mat4::orthographic`] (Gribb and Hartmann). Works for perspective and
+    ///orthographic projections, with GL's clip depth in `[-w, w]`.
+    pub fn from_matrix(m: &Mat4<S>) -> Self {
+        let row = |i: usize| Vector(m.0[i]);
+        let w = row(3);
+        let plane = |v: Vector<4, S>| Plane::new(Vector([v.0[0], v.0[1], v.0[2]]), -v.0[3]);
+        Self {
+            planes: [
+                plane(w + row(0)),
+                plane(w - row(0)),
+                plane(w + row(1)),
+                plane(w - row(1)),
+                plane(w + row(2)),
+                plane(w - row(2)),
+            ],
+        }
+    }
+
+    ///Same as [`Frustum::from_matrix`] for a matrix laid out for upload to GL, which reads our rows as its columns.
+    ///[`Mat4::perspective`] and [`Mat4::look_at`] are built that way, so their product `look_at * perspective` goes here.
+    pub fn from_gl_matrix(m: &Mat4<S>) -> Self {
+        Self::from_matrix(&m.transpose())
+    }
+
+    ///Points on the boundary are inside.
+    pub fn contains(&self, point: Vector3<S>) -> bool {
+        self.planes.iter().all(|p| p.signed_distance(point) >= S::ZERO)
+    }
+
+    pub fn classify_point(&self, point: Vector3<S>) -> Containment {
+        if self.contains(point) { Containment::Inside } else { Containment::Outside }
+    }
+
+    pub fn classify_sphere(&self, sphere: &Sphere<S>) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let d = plane.signed_distance(sphere.center);
+            if d < -sphere.radius {
+                return Containment::Outside;
+            }
+            if d < sphere.radius {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    ///Tests the corners nearest to and farthest from each plane. Conservative: a box outside the frustum near one of
+    ///its edges, but not entirely behind a single plane, is reported as intersecting.
+    pub fn classify_aabb(&self, aabb: &Aabb3<S>) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let (mut farthest, mut nearest) = (aabb.min, aabb.max);
+            for i in 0..3 {
+                if plane.normal.0[i] >= S::ZERO {
+                    farthest.0[i] = aabb.max.0[i];
+                    nearest.0[i] = aabb.min.0[i];
+                }
+            }
+            if plane.signed_distance(farthest) < S::ZERO {
+                return Containment::Outside;
+            }
+            if plane.signed_distance(nearest) < S::ZERO {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    ///Classifies every sphere into `out`, which is cleared first so it can be reused from frame to frame.
+    pub fn classify_spheres(&self, spheres: &[Sphere<S>], out: &mut Vec<Containment>) {
+        out.clear();
+        out.extend(spheres.iter().map(|s| self.classify_sphere(s)));
+    }
+
+    ///Classifies every box into `out`, which is cleared first so it can be reused from frame to frame.
+    pub fn classify_aabbs(&self, aabbs: &[Aabb3<S>], out: &mut Vec<Containment>) {
+        out.clear();
+        out.extend(aabbs.iter().map(|a| self.classify_aabb(a)));
+    }
+}
@@ -0,0 +1,321 @@
+use crate::geometry::{tolerance, Contact, SupportMap};
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector3};
+
+const GJK_MAX_ITERATIONS: usize = 64;
+const EPA_MAX_ITERATIONS: usize = 128;
+
+///Closest points of two separated shapes, `point_a` on the first and `point_b` on the second.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Separation<S: Scalar> {
+    pub distance: S,
+    pub point_a: Vector3<S>,
+    pub point_b: Vector3<S>,
+}
+
+///A point of the Minkowski difference <i>A - B</i>, with the support points it came from.
+#[derive(Debug, Clone, Copy)]
+struct Vertex<S: Scalar> {
+    w: Vector3<S>,
+    a: Vector3<S>,
+    b: Vector3<S>,
+}
+
+enum Outcome<S: Scalar> {
+    Separated(Separation<S>),
+    ///The final simplex, which contains the origin.
+    Intersecting(Vec<Vertex<S>>),
+}
+
+fn support<S: Scalar, A: SupportMap<S> + ?Sized, B: SupportMap<S> + ?Sized>(a: &A, b: &B, direction: Vector3<S>) -> Vertex<S> {
+    let (pa, pb) = (a.support(direction), b.support(-direction));
+    Vertex { w: pa - pb, a: pa, b: pb }
+}
+
+///Diagonal of the bounding box of the Minkowski difference, which the seeding and EPA thresholds scale with.
+fn extent<S: Scalar, A: SupportMap<S> + ?Sized, B: SupportMap<S> + ?Sized>(a: &A, b: &B) -> S {
+    let axes = [Vector3::RIGHT, Vector3::UP, Vector3::FORWARD];
+    let size: Vector3<S> = Vector(std::array::from_fn(|i| support(a, b, axes[i]).w.0[i] - support(a, b, -axes[i]).w.0[i]));
+    size.magnitude()
+}
+
+///Whether the shapes overlap or touch (Gilbert–Johnson–Keerthi).
+pub fn gjk_intersects<S: Scalar, A: SupportMap<S> + ?Sized, B: SupportMap<S> + ?Sized>(a: &A, b: &B) -> bool {
+    matches!(gjk(a, b), Outcome::Intersecting(_))
+}
+
+///Distance and closest points of two convex shapes (Gilbert–Johnson–Keerthi), `None` if they overlap or touch.
+pub fn gjk_distance<S: Scalar, A: SupportMap<S> + ?Sized, B: SupportMap<S> + ?Sized>(a: &A, b: &B) -> Option<Separation<S>> {
+    match gjk(a, b) {
+        Outcome::Separated(separation) => Some(separation),
+        Outcome::Intersecting(_) => None,
+    }
+}
+
+///Penetration of two overlapping convex shapes with the Expanding Polytope Algorithm, seeded by the final GJK
+///simplex. Returns `None` if they are separated.
+pub fn epa<S: Scalar, A: SupportMap<S> + ?Sized, B: SupportMap<S> + ?Sized>(a: &A, b: &B) -> Option<Contact<S>> {
+    let simplex = match gjk(a, b) {
+        Outcome::Separated(_) => return None,
+        Outcome::Intersecting(simplex) => simplex,
+    };
+    let vertices = match tetrahedron(a, b, simplex) {
+        Ok(vertices) => vertices,
+        //The Minkowski difference is flat, so the shapes only touch
+        Err(normal) => return Some(Contact { normal, depth: S::ZERO }),
+    };
+    Some(expand(a, b, vertices))
+}
+
+fn gjk<S: Scalar, A: SupportMap<S> + ?Sized, B: SupportMap<S> + ?Sized>(a: &A, b: &B) -> Outcome<S> {
+    let first = support(a, b, Vector3::RIGHT);
+    let mut simplex = vec![first];
+    let mut weights = vec![S::ONE];
+    let mut v = first.w;
+    let tolerance = tolerance::<S>();
+
+    for _ in 0..GJK_MAX_ITERATIONS {
+        let vv = v.magnitude_squared();
+        let scale = simplex.iter().map(|s| s.w.magnitude_squared()).fold(S::ZERO, |m, x| if x > m { x } else { m });
+        if vv <= S::EPSILON * scale || vv == S::ZERO {
+            return Outcome::Intersecting(simplex);
+        }
+
+        let w = support(a, b, -v);
+        //No support point gets meaningfully closer to the origin than v
+        if vv - v.dot_product(w.w) <= tolerance * vv || simplex.iter().any(|s| s.w == w.w) {
+            break;
+        }
+
+        simplex.push(w);
+        match closest_to_origin(&mut simplex) {
+            Some(reduced) => weights = reduced,
+            None => return Outcome::Intersecting(simplex),
+        }
+        v = combine(&simplex, &weights, |s| s.w);
+    }
+
+    let point_a = combine(&simplex, &weights, |s| s.a);
+    let point_b = combine(&simplex, &weights, |s| s.b);
+    Outcome::Separated(Separation { distance: v.magnitude(), point_a, point_b })
+}
+
+fn combine<S: Scalar, F: Fn(&Vertex<S>) -> Vector3<S>>(simplex: &[Vertex<S>], weights: &[S], f: F) -> Vector3<S> {
+    simplex.iter().zip(weights).fold(Vector::ZERO, |sum, (s, l)| sum + f(s) * *l)
+}
+
+///Reduces the simplex to the face, edge or vertex holding its point closest to the origin and returns that point's
+///barycentric weights. `None` if the simplex is a tetrahedron enclosing the origin.
+fn closest_to_origin<S: Scalar>(simplex: &mut Vec<Vertex<S>>) -> Option<Vec<S>> {
+    match simplex.len() {
+        1 => Some(vec![S::ONE]),
+        2 => Some(closest_on_segment(simplex, 0, 1)),
+        3 => Some(closest_on_triangle(simplex, 0, 1, 2)),
+        _ => closest_on_tetrahedron(simplex),
+    }
+}
+
+///Keeps only `keep` from the simplex, in that order.
+fn retain<S: Scalar>(simplex: &mut Vec<Vertex<S>>, keep: &[usize]) {
+    let kept: Vec<Vertex<S>> = keep.iter().map(|i| simplex[*i]).collect();
+    *simplex = kept;
+}
+
+fn closest_on_segment<S: Scalar>(simplex: &mut Vec<Vertex<S>>, i: usize, j: usize) -> Vec<S> {
+    let (p, q) = (simplex[i].w, simplex[j].w);
+    let pq = q - p;
+    let length = pq.magnitude_squared();
+    let t = if length > S::ZERO { -p.dot_product(pq) / length } else { S::ZERO };
+    if t <= S::ZERO {
+        retain(simplex, &[i]);
+        vec![S::ONE]
+    } else if t >= S::ONE {
+        retain(simplex, &[j]);
+        vec![S::ONE]
+    } else {
+        retain(simplex, &[i, j]);
+        vec![S::ONE - t, t]
+    }
+}
+
+///Ericson's Voronoi region test, 5.1.5, for the origin.
+fn closest_on_triangle<S: Scalar>(simplex: &mut Vec<Vertex<S>>, i: usize, j: usize, k: usize) -> Vec<S> {
+    let zero = S::ZERO;
+    let (a, b, c) = (simplex[i].w, simplex[j].w, simplex[k].w);
+    let (ab, ac) = (b - a, c - a);
+
+    let (d1, d2) = (ab.dot_product(-a), ac.dot_product(-a));
+    if d1 <= zero && d2 <= zero {
+        retain(simplex, &[i]);
+        return vec![S::ONE];
+    }
+    let (d3, d4) = (ab.dot_product(-b), ac.dot_product(-b));
+    if d3 >= zero && d4 <= d3 {
+        retain(simplex, &[j]);
+        return vec![S::ONE];
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= zero && d1 >= zero && d3 <= zero {
+        let v = d1 / (d1 - d3);
+        retain(simplex, &[i, j]);
+        return vec![S::ONE - v, v];
+    }
+    let (d5, d6) = (ab.dot_product(-c), ac.dot_product(-c));
+    if d6 >= zero && d5 <= d6 {
+        retain(simplex, &[k]);
+        return vec![S::ONE];
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= zero && d2 >= zero && d6 <= zero {
+        let w = d2 / (d2 - d6);
+        retain(simplex, &[i, k]);
+        return vec![S::ONE - w, w];
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        retain(simplex, &[j, k]);
+        return vec![S::ONE - w, w];
+    }
+
+    let sum = va + vb + vc;
+    if sum <= zero {
+        //Degenerate triangle: fall back to its longest edge
+        let edges = [(i, j), (i, k), (j, k)];
+        let (p, q) = edges.into_iter().fold(edges[0], |best, (p, q)| {
+            let length = |(p, q): (usize, usize)| (simplex[q].w - simplex[p].w).magnitude_squared();
+            if length((p, q)) > length(best) { (p, q) } else { best }
+        });
+        return closest_on_segment(simplex, p, q);
+    }
+    let (v, w) = (vb / sum, vc / sum);
+    retain(simplex, &[i, j, k]);
+    vec![S::ONE - v - w, v, w]
+}
+
+fn closest_on_tetrahedron<S: Scalar>(simplex: &mut Vec<Vertex<S>>) -> Option<Vec<S>> {
+    let faces = [(0, 1, 2, 3), (0, 1, 3, 2), (0, 2, 3, 1), (1, 2, 3, 0)];
+    let mut best: Option<(S, Vec<Vertex<S>>, Vec<S>)> = None;
+
+    for (i, j, k, opposite) in faces {
+        let (a, b, c, d) = (simplex[i].w, simplex[j].w, simplex[k].w, simplex[opposite].w);
+        let normal = (b - a).cross_product(c - a);
+        let origin_side = -normal.dot_product(a);
+        let opposite_side = normal.dot_product(d - a);
+        //The origin can only be closest to faces it is in front of, seen from outside. A flat tetrahedron
+        //has no inside, so all of its faces are tried.
+        if origin_side * opposite_side > S::ZERO {
+            continue;
+        }
+        let mut face = simplex.clone();
+        let weights = closest_on_triangle(&mut face, i, j, k);
+        let distance = combine(&face, &weights, |s| s.w).magnitude_squared();
+        if best.as_ref().is_none_or(|(d, _, _)| distance < *d) {
+            best = Some((distance, face, weights));
+        }
+    }
+
+    let (_, face, weights) = best?;
+    *simplex = face;
+    Some(weights)
+}
+
+///Grows a GJK simplex holding the origin into a tetrahedron, or returns a normal of the flat Minkowski difference.
+fn tetrahedron<S: Scalar, A: SupportMap<S> + ?Sized, B: SupportMap<S> + ?Sized>(a: &A, b: &B, mut simplex: Vec<Vertex<S>>) -> Result<Vec<Vertex<S>>, Vector3<S>> {
+    let tolerance = tolerance::<S>() * extent(a, b);
+    let axes = [Vector3::RIGHT, Vector3::UP, Vector3::FORWARD];
+
+    if simplex.len() == 1 {
+        let origin = simplex[0].w;
+        let found = axes.into_iter().flat_map(|d| [d, -d]).map(|d| support(a, b, d)).find(|v| v.w.distance(origin) > tolerance);
+        simplex.push(found.ok_or(Vector3::UP)?);
+    }
+    if simplex.len() == 2 {
+        let line = simplex[1].w - simplex[0].w;
+        let helper = axes.into_iter().fold(axes[0], |best, d| if d.dot_product(line).absolute() < best.dot_product(line).absolute() { d } else { best });
+        let u = line.cross_product(helper).normalized();
+        let v = line.normalized().cross_product(u);
+        let off_line = |p: Vector3<S>| (p - simplex[0].w).cross_product(line.normalized()).magnitude() > tolerance;
+        let found = [u, -u, v, -v].into_iter().map(|d| support(a, b, d)).find(|s| off_line(s.w));
+        simplex.push(found.ok_or(u)?);
+    }
+    if simplex.len() == 3 {
+        let normal = (simplex[1].w - simplex[0].w).cross_product(simplex[2].w - simplex[0].w).normalized();
+        let off_plane = |p: Vector3<S>| normal.dot_product(p - simplex[0].w).absolute() > tolerance;
+        let found = [normal, -normal].into_iter().map(|d| support(a, b, d)).find(|s| off_plane(s.w));
+        simplex.push(found.ok_or(normal)?);
+    }
+    Ok(simplex)
+}
+
+///A face of the expanding polytope, wound counter-clockwise seen from outside.
+struct Face<S: Scalar> {
+    indices: [usize; 3],
+    normal: Vector3<S>,
+    distance: S,
+}
+
+impl<S: Scalar> Face<S> {
+    fn new(vertices: &[Vertex<S>], indices: [usize; 3]) -> Self {
+        let [a, b, c] = indices.map(|i| vertices[i].w);
+        let normal = (b - a).cross_product(c - a);
+        let length = normal.magnitude();
+        if length <= S::ZERO {
+            return Self { indices, normal: Vector::ZERO, distance: S::INFINITY };
+        }
+        let normal = normal / length;
+        Self { indices, normal, distance: normal.dot_product(a) }
+    }
+}
+
+fn expand<S: Scalar, A: SupportMap<S> + ?Sized, B: SupportMap<S> + ?Sized>(a: &A, b: &B, mut vertices: Vec<Vertex<S>>) -> Contact<S> {
+    let tolerance = tolerance::<S>() * extent(a, b);
+
+    //Wind the four faces outwards
+    let mut faces = Vec::new();
+    for (i, j, k, opposite) in [(0, 1, 2, 3), (0, 3, 1, 2), (0, 2, 3, 1), (1, 3, 2, 0)] {
+        let (p, q, r, o) = (vertices[i].w, vertices[j].w, vertices[k].w, vertices[opposite].w);
+        let inward = (q - p).cross_product(r - p).dot_product(o - p) > S::ZERO;
+        faces.push(Face::new(&vertices, if inward { [i, k, j] } else { [i, j, k] }));
+    }
+
+    let closest = |faces: &[Face<S>]| (0..faces.len()).fold(0, |best, f| if faces[f].distance < faces[best].distance { f } else { best });
+    for _ in 0..EPA_MAX_ITERATIONS {
+        let face = &faces[closest(&faces)];
+        let w = support(a, b, face.normal);
+        if w.w.dot_product(face.normal) - face.distance <= tolerance {
+            break;
+        }
+
+        let index = vertices.len();
+        vertices.push(w);
+
+        let mut horizon: Vec<(usize, usize)> = Vec::new();
+        faces.retain(|f| {
+            let visible = f.normal.dot_product(w.w - vertices[f.indices[0]].w) > S::ZERO;
+            if visible {
+                for e in 0..3 {
+                    let edge = (f.indices[e], f.indices[(e + 1) % 3]);
+                    //An edge shared by two visible faces appears once in each direction and is not on the horizon
+                    match horizon.iter().position(|h| *h == (edge.1, edge.0)) {
+                        Some(shared) => {
+                            horizon.swap_remove(shared);
+                        }
+                        None => horizon.push(edge),
+                    }
+                }
+            }
+            !visible
+        });
+        if horizon.is_empty() {
+            break;
+        }
+        for (p, q) in horizon {
+            faces.push(Face::new(&vertices, [p, q, index]));
+        }
+    }
+
+    let face = &faces[closest(&faces)];
+    let depth = if face.distance > S::ZERO { face.distance } else { S::ZERO };
+    Contact { normal: face.normal, depth }
+}
@@ -0,0 +1,244 @@
+use std::cmp::Ordering;
+
+use crate::geometry::{tolerance, Aabb3, SupportMap};
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector2, Vector3};
+
+///A convex hull as an indexed triangle mesh, with every triangle wound counter-clockwise seen from outside.
+///
+///Degenerate input still gives a well-defined result:
+///- no points: no vertices and no triangles,
+///- coincident points: one vertex and no triangles,
+///- collinear points: the two extreme vertices and no triangles,
+///- coplanar points: the convex polygon, triangulated once facing each side of the plane. The mesh is still
+///  closed, but has no volume.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexHull<S: Scalar> {
+    pub vertices: Vec<Vector3<S>>,
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl<S: Scalar> ConvexHull<S> {
+    pub fn new(points: &[Vector3<S>]) -> Self {
+        quickhull(points)
+    }
+
+    ///Whether `point` is inside or on the hull, with the tolerance used to build it. Always `false` for hulls
+    ///without triangles.
+    pub fn contains(&self, point: Vector3<S>) -> bool {
+        let tolerance = hull_tolerance(&self.vertices);
+        !self.triangles.is_empty() && self.triangles.iter().all(|t| {
+            let [a, b, c] = t.map(|i| self.vertices[i]);
+            let normal = (b - a).cross_product(c - a).normalized();
+            normal.dot_product(point - a) <= tolerance
+        })
+    }
+}
+
+impl<S: Scalar> SupportMap<S> for ConvexHull<S> {
+    fn support(&self, direction: Vector3<S>) -> Vector3<S> {
+        self.vertices.support(direction)
+    }
+}
+
+///Distance below which points count as on a plane, line or point, relative to the extent of the input.
+fn hull_tolerance<S: Scalar>(points: &[Vector3<S>]) -> S {
+    Aabb3::from_points(points).map_or(S::ZERO, |bounds| tolerance::<S>() * bounds.size().magnitude())
+}
+
+struct Face<S: Scalar> {
+    indices: [usize; 3],
+    normal: Vector3<S>,
+    offset: S,
+    outside: Vec<usize>,
+}
+
+impl<S: Scalar> Face<S> {
+    fn new(points: &[Vector3<S>], indices: [usize; 3]) -> Self {
+        let [a, b, c] = indices.map(|i| points[i]);
+        let normal = (b - a).cross_product(c - a).normalized();
+        Self { indices, normal, offset: normal.dot_product(a), outside: Vec::new() }
+    }
+
+    fn distance(&self, point: Vector3<S>) -> S {
+        self.normal.dot_product(point) - self.offset
+    }
+}
+
+///Quickhull (Barber, Dobkin and Huhdanpaa).
+pub fn quickhull<S: Scalar>(points: &[Vector3<S>]) -> ConvexHull<S> {
+    let tolerance = hull_tolerance(points);
+    let Some(&first) = points.first() else {
+        return ConvexHull { vertices: Vec::new(), triangles: Vec::new() };
+    };
+
+    //Initial simplex: the farthest apart axis extremes, then the farthest points from their line and plane
+    let mut extremes = Vec::new();
+    for axis in 0..3 {
+        extremes.push(farthest_by(points, |p| -p.0[axis]));
+        extremes.push(farthest_by(points, |p| p.0[axis]));
+    }
+    let mut pair = (extremes[0], extremes[1]);
+    for &i in &extremes {
+        for &j in &extremes {
+            if points[i].distance_squared(points[j]) > points[pair.0].distance_squared(points[pair.1]) {
+                pair = (i, j);
+            }
+        }
+    }
+    let (i0, i1) = pair;
+    if points[i0].distance(points[i1]) <= tolerance {
+        return ConvexHull { vertices: vec![first], triangles: Vec::new() };
+    }
+
+    let line = (points[i1] - points[i0]).normalized();
+    let i2 = farthest_by(points, |p| (p - points[i0]).cross_product(line).magnitude());
+    if (points[i2] - points[i0]).cross_product(line).magnitude() <= tolerance {
+        let low = farthest_by(points, |p| -p.dot_product(line));
+        let high = farthest_by(points, |p| p.dot_product(line));
+        return ConvexHull { vertices: vec![points[low], points[high]], triangles: Vec::new() };
+    }
+
+    let normal = line.cross_product(points[i2] - points[i0]).normalized();
+    let i3 = farthest_by(points, |p| normal.dot_product(p - points[i0]).absolute());
+    if normal.dot_product(points[i3] - points[i0]).absolute() <= tolerance {
+        return planar_hull(points, points[i0], line, normal);
+    }
+
+    //Tetrahedron with outward faces
+    let centroid = (points[i0] + points[i1] + points[i2] + points[i3]) / S::from_f32(4.0);
+    let mut faces: Vec<Face<S>> = [[i0, i1, i2], [i0, i3, i1], [i0, i2, i3], [i1, i3, i2]]
+        .into_iter()
+        .map(|[a, b, c]| {
+            let face = Face::new(points, [a, b, c]);
+            if face.distance(centroid) > S::ZERO { Face::new(points, [a, c, b]) } else { face }
+        })
+        .collect();
+
+    let simplex = [i0, i1, i2, i3];
+    let candidates: Vec<usize> = (0..points.len()).filter(|i| !simplex.contains(i)).collect();
+    assign(points, &mut faces, candidates, tolerance);
+
+    while let Some(current) = faces.iter().position(|f| !f.outside.is_empty()) {
+        let face = &faces[current];
+        let eye = face.outside.iter().copied().fold(face.outside[0], |best, i| {
+            if face.distance(points[i]) > face.distance(points[best]) { i } else { best }
+        });
+
+        let (visible, kept): (Vec<Face<S>>, Vec<Face<S>>) = faces.into_iter().partition(|f| f.distance(points[eye]) > tolerance);
+        faces = kept;
+
+        //Edges of the visible region that are not shared by two visible faces
+        let mut horizon: Vec<(usize, usize)> = Vec::new();
+        for f in &visible {
+            for e in 0..3 {
+                let edge = (f.indices[e], f.indices[(e + 1) % 3]);
+                match horizon.iter().position(|h| *h == (edge.1, edge.0)) {
+                    Some(shared) => {
+                        horizon.remove(shared);
+                    }
+                    None => horizon.push(edge),
+                }
+            }
+        }
+
+        let first_new = faces.len();
+        faces.extend(horizon.into_iter().map(|(a, b)| Face::new(points, [a, b, eye])));
+        let orphans: Vec<usize> = visible.into_iter().flat_map(|f| f.outside).filter(|i| *i != eye).collect();
+        assign(points, &mut faces[first_new..], orphans, tolerance);
+    }
+
+    //Keep only the points used by the hull
+    let mut remap = vec![usize::MAX; points.len()];
+    let mut vertices = Vec::new();
+    let triangles = faces
+        .iter()
+        .map(|f| {
+            f.indices.map(|i| {
+                if remap[i] == usize::MAX {
+                    remap[i] = vertices.len();
+                    vertices.push(points[i]);
+                }
+                remap[i]
+            })
+        })
+        .collect();
+    ConvexHull { vertices, triangles }
+}
+
+///Gives each point to the first face it is outside of. Points outside none of them are inside the hull.
+fn assign<S: Scalar>(points: &[Vector3<S>], faces: &mut [Face<S>], candidates: Vec<usize>, tolerance: S) {
+    for i in candidates {
+        if let Some(face) = faces.iter_mut().find(|f| f.distance(points[i]) > tolerance) {
+            face.outside.push(i);
+        }
+    }
+}
+
+fn farthest_by<S: Scalar, F: Fn(Vector3<S>) -> S>(points: &[Vector3<S>], key: F) -> usize {
+    let mut best = (0, key(points[0]));
+    for (i, p) in points.iter().enumerate().skip(1) {
+        let k = key(*p);
+        if k > best.1 {
+            best = (i, k);
+        }
+    }
+    best.0
+}
+
+fn planar_hull<S: Scalar>(points: &[Vector3<S>], origin: Vector3<S>, u: Vector3<S>, normal: Vector3<S>) -> ConvexHull<S> {
+    let v = normal.cross_product(u);
+    let projected: Vec<Vector2<S>> = points.iter().map(|p| Vector([u.dot_product(*p - origin), v.dot_product(*p - origin)])).collect();
+    let order = monotone_chain(&projected);
+    let vertices: Vec<Vector3<S>> = order.iter().map(|i| points[*i]).collect();
+
+    //The polygon is counter-clockwise seen from the normal: a fan facing it, and one facing away around a
+    //different apex, so that every edge is shared by exactly two triangles
+    let n = vertices.len();
+    let mut triangles = Vec::new();
+    for i in 1..n - 1 {
+        triangles.push([0, i, i + 1]);
+    }
+    for i in 2..n {
+        triangles.push([1, (i + 1) % n, i]);
+    }
+    ConvexHull { vertices, triangles }
+}
+
+///Andrew's monotone chain. Returns the hull counter-clockwise, starting from the lowest `x` (then `y`), without
+///points lying on its edges. Coincident points give one point and collinear points their two extremes.
+pub fn convex_hull_2d<S: Scalar>(points: &[Vector2<S>]) -> Vec<Vector2<S>> {
+    monotone_chain(points).into_iter().map(|i| points[i]).collect()
+}
+
+fn monotone_chain<S: Scalar>(points: &[Vector2<S>]) -> Vec<usize> {
+    let compare = |a: &usize, b: &usize| {
+        let (p, q) = (points[*a], points[*b]);
+        p.0[0].partial_cmp(&q.0[0]).unwrap_or(Ordering::Equal)
+            .then(p.0[1].partial_cmp(&q.0[1]).unwrap_or(Ordering::Equal))
+    };
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(compare);
+    order.dedup_by(|a, b| points[*a] == points[*b]);
+    if order.len() < 3 {
+        return order;
+    }
+
+    let cross = |o: usize, a: usize, b: usize| {
+        let (o, a, b) = (points[o], points[a], points[b]);
+        (a.0[0] - o.0[0]) * (b.0[1] - o.0[1]) - (a.0[1] - o.0[1]) * (b.0[0] - o.0[0])
+    };
+    let mut hull: Vec<usize> = Vec::with_capacity(2 * order.len());
+    //Lower chain left to right, then the upper chain back
+    for pass in [order.clone(), order.iter().rev().copied().collect()] {
+        let start = hull.len();
+        for i in pass {
+            while hull.len() >= start + 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], i) <= S::ZERO {
+                hull.pop();
+            }
+            hull.push(i);
+        }
+        hull.pop();
+    }
+    hull
+}
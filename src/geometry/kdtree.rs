@@ -0,0 +1,208 @@
+use std::cmp::Ordering;
+
+use crate::geometry::Aabb;
+use crate::scalar::Scalar;
+use crate::vector::Vector;
+
+///A distance between points, for the nearest-neighbor and radius queries of a [`KdTree`].
+pub trait Metric<const L: usize, S: Scalar> {
+    fn distance(&self, a: Vector<L, S>, b: Vector<L, S>) -> S;
+
+    ///A lower bound on the distance between points whose coordinates along some axis differ by `delta`, used to
+    ///skip the far side of a split. The default suits any metric at least as large as each coordinate difference.
+    fn axis_distance(&self, delta: S) -> S {
+        delta.absolute()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Euclidean;
+
+///Sum of the absolute coordinate differences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Manhattan;
+
+///Largest absolute coordinate difference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Chebyshev;
+
+impl<const L: usize, S: Scalar> Metric<L, S> for Euclidean {
+    fn distance(&self, a: Vector<L, S>, b: Vector<L, S>) -> S {
+        a.distance(b)
+    }
+}
+
+impl<const L: usize, S: Scalar> Metric<L, S> for Manhattan {
+    fn distance(&self, a: Vector<L, S>, b: Vector<L, S>) -> S {
+        (0..L).fold(S::ZERO, |sum, i| sum + (a.0[i] - b.0[i]).absolute())
+    }
+}
+
+impl<const L: usize, S: Scalar> Metric<L, S> for Chebyshev {
+    fn distance(&self, a: Vector<L, S>, b: Vector<L, S>) -> S {
+        (0..L).fold(S::ZERO, |max, i| {
+            let d = (a.0[i] - b.0[i]).absolute();
+            if d > max { d } else { max }
+        })
+    }
+}
+
+///A point found by a query, with its item and its distance from the query point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Neighbor<'a, const L: usize, S: Scalar, T> {
+    pub point: Vector<L, S>,
+    pub item: &'a T,
+    pub distance: S,
+}
+
+///Static k-d tree over points carrying an item each. Stored implicitly: the point of a node is the median of
+///its range along the axis where the range spreads the most, with the lower half before it and the upper after.
+#[derive(Debug, Clone)]
+pub struct KdTree<const L: usize, S: Scalar, T> {
+    entries: Vec<(Vector<L, S>, T)>,
+    ///The split axis of the node at each index.
+    axes: Vec<usize>,
+}
+
+impl<const L: usize, S: Scalar> KdTree<L, S, usize> {
+    ///A tree whose items are the indices of the points in `points`.
+    pub fn from_points(points: &[Vector<L, S>]) -> Self {
+        Self::from_entries(points.iter().copied().enumerate().map(|(i, p)| (p, i)).collect())
+    }
+}
+
+impl<const L: usize, S: Scalar, T: Clone> KdTree<L, S, T> {
+    pub fn new(entries: &[(Vector<L, S>, T)]) -> Self {
+        Self::from_entries(entries.to_vec())
+    }
+}
+
+impl<const L: usize, S: Scalar, T> KdTree<L, S, T> {
+    pub fn from_entries(mut entries: Vec<(Vector<L, S>, T)>) -> Self {
+        let mut axes = vec![0; entries.len()];
+        build(&mut entries, &mut axes);
+        Self { entries, axes }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    ///The `k` points nearest to `point` by Euclidean distance, nearest first.
+    pub fn nearest(&self, point: Vector<L, S>, k: usize) -> Vec<Neighbor<'_, L, S, T>> {
+        self.nearest_by(point, k, &Euclidean)
+    }
+
+    ///The `k` points nearest to `point` by `metric`, nearest first. Ties are broken arbitrarily.
+    pub fn nearest_by<M: Metric<L, S>>(&self, point: Vector<L, S>, k: usize, metric: &M) -> Vec<Neighbor<'_, L, S, T>> {
+        let mut best: Vec<(S, usize)> = Vec::with_capacity(k + 1);
+        if k > 0 {
+            self.search_nearest(0, self.entries.len(), point, k, metric, &mut best);
+        }
+        best.into_iter().map(|(distance, i)| self.neighbor(i, distance)).collect()
+    }
+
+    fn search_nearest<M: Metric<L, S>>(&self, lo: usize, hi: usize, point: Vector<L, S>, k: usize, metric: &M, best: &mut Vec<(S, usize)>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let distance = metric.distance(point, self.entries[mid].0);
+        if best.len() < k || distance < best[best.len() - 1].0 {
+            let at = best.partition_point(|(d, _)| *d <= distance);
+            best.insert(at, (distance, mid));
+            best.truncate(k);
+        }
+
+        let delta = point.0[self.axes[mid]] - self.entries[mid].0 .0[self.axes[mid]];
+        let (near, far) = if delta < S::ZERO { ((lo, mid), (mid + 1, hi)) } else { ((mid + 1, hi), (lo, mid)) };
+        self.search_nearest(near.0, near.1, point, k, metric, best);
+        if best.len() < k || metric.axis_distance(delta) <= best[best.len() - 1].0 {
+            self.search_nearest(far.0, far.1, point, k, metric, best);
+        }
+    }
+
+    ///The points within Euclidean distance `radius` of `point`, boundary included, nearest first.
+    pub fn within_radius(&self, point: Vector<L, S>, radius: S) -> Vec<Neighbor<'_, L, S, T>> {
+        self.within_radius_by(point, radius, &Euclidean)
+    }
+
+    ///The points within `radius` of `point` by `metric`, boundary included, nearest first.
+    pub fn within_radius_by<M: Metric<L, S>>(&self, point: Vector<L, S>, radius: S, metric: &M) -> Vec<Neighbor<'_, L, S, T>> {
+        let mut found = Vec::new();
+        self.search_radius(0, self.entries.len(), point, radius, metric, &mut found);
+        found.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        found.into_iter().map(|(distance, i)| self.neighbor(i, distance)).collect()
+    }
+
+    fn search_radius<M: Metric<L, S>>(&self, lo: usize, hi: usize, point: Vector<L, S>, radius: S, metric: &M, found: &mut Vec<(S, usize)>) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let distance = metric.distance(point, self.entries[mid].0);
+        if distance <= radius {
+            found.push((distance, mid));
+        }
+
+        let delta = point.0[self.axes[mid]] - self.entries[mid].0 .0[self.axes[mid]];
+        let (near, far) = if delta < S::ZERO { ((lo, mid), (mid + 1, hi)) } else { ((mid + 1, hi), (lo, mid)) };
+        self.search_radius(near.0, near.1, point, radius, metric, found);
+        if metric.axis_distance(delta) <= radius {
+            self.search_radius(far.0, far.1, point, radius, metric, found);
+        }
+    }
+
+    ///The entries whose points are inside `aabb`, boundary included, in no particular order.
+    pub fn in_aabb(&self, aabb: &Aabb<L, S>) -> Vec<&(Vector<L, S>, T)> {
+        let mut found = Vec::new();
+        let mut stack = vec![(0, self.entries.len())];
+        while let Some((lo, hi)) = stack.pop() {
+            if lo >= hi {
+                continue;
+            }
+            let mid = lo + (hi - lo) / 2;
+            let (point, axis) = (self.entries[mid].0, self.axes[mid]);
+            if aabb.contains(point) {
+                found.push(&self.entries[mid]);
+            }
+            if aabb.min.0[axis] <= point.0[axis] {
+                stack.push((lo, mid));
+            }
+            if aabb.max.0[axis] >= point.0[axis] {
+                stack.push((mid + 1, hi));
+            }
+        }
+        found
+    }
+
+    fn neighbor(&self, i: usize, distance: S) -> Neighbor<'_, L, S, T> {
+        Neighbor { point: self.entries[i].0, item: &self.entries[i].1, distance }
+    }
+}
+
+fn build<const L: usize, S: Scalar, T>(entries: &mut [(Vector<L, S>, T)], axes: &mut [usize]) {
+    if entries.is_empty() {
+        return;
+    }
+    let mut low = entries[0].0;
+    let mut high = entries[0].0;
+    for (p, _) in entries.iter() {
+        low = low.min(*p);
+        high = high.max(*p);
+    }
+    let spread = high - low;
+    let axis = (0..L).fold(0, |best, i| if spread.0[i] > spread.0[best] { i } else { best });
+
+    let mid = entries.len() / 2;
+    entries.select_nth_unstable_by(mid, |a, b| a.0 .0[axis].partial_cmp(&b.0 .0[axis]).unwrap_or(Ordering::Equal));
+    axes[mid] = axis;
+    let (entries_low, rest) = entries.split_at_mut(mid);
+    let (axes_low, axes_rest) = axes.split_at_mut(mid);
+    build(entries_low, axes_low);
+    build(&mut rest[1..], &mut axes_rest[1..]);
+}
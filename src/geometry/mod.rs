@@ -0,0 +1,71 @@
+use crate::matrix::mat3::Mat3;
+use crate::matrix::mat4::Mat4;
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::scalar::Scalar;
+
+pub mod aabb;
+pub mod bvh;
+pub mod capsule;
+pub mod fitting;
+pub mod gjk;
+pub mod hull;
+pub mod kdtree;
+pub mod frustum;
+pub mod obb;
+pub mod overlap;
+pub mod plane;
+pub mod polygon;
+pub mod ray;
+pub mod raycast;
+pub mod segment;
+pub mod sphere;
+pub mod support;
+pub mod triangle;
+
+pub use aabb::{Aabb, Aabb2, Aabb3};
+pub use bvh::Bvh;
+pub use capsule::Capsule;
+pub use frustum::{Containment, Frustum};
+pub use hull::ConvexHull;
+pub use kdtree::{KdTree, Metric, Neighbor};
+pub use obb::Obb;
+pub use overlap::Contact;
+pub use plane::Plane;
+pub use polygon::{Polygon2, Winding};
+pub use ray::Ray;
+pub use raycast::{RayCast, RayHit, TriangleHit};
+pub use segment::Segment;
+pub use sphere::Sphere;
+pub use support::{MinkowskiSum, SupportMap};
+pub use triangle::Triangle;
+
+///Transformation of shapes by an affine `Mat4` (column vector convention, translation in the last column) or a
+///rotation about the origin.
+pub trait Transform<S: Scalar>: Sized {
+    ///Shapes that cannot represent the result exactly, like a sphere under a non-uniform scale, grow to enclose it.
+    fn transformed(&self, m: &Mat4<S>) -> Self;
+
+    fn rotated(&self, q: &Quaternion<S>) -> Self;
+}
+
+///Distance within which thin shapes (rays, planes, segments and triangles) are considered to contain a point.
+pub fn tolerance<S: Scalar>() -> S {
+    S::EPSILON.square_root()
+}
+
+///The upper-left 3×3 block of `m`.
+pub(crate) fn linear_part<S: Scalar>(m: &Mat4<S>) -> Mat3<S> {
+    let mut out: Mat3<S> = Matrix::ZERO;
+    for i in 0..3 {
+        for j in 0..3 {
+            out.0[i][j] = m.0[i][j];
+        }
+    }
+    out
+}
+
+///The largest factor by which `m` stretches a direction, its largest singular value.
+pub(crate) fn max_scale<S: Scalar>(m: &Mat4<S>) -> S {
+    linear_part(m).svd().sigma.0[0]
+}
@@ -0,0 +1,131 @@
+use crate::geometry::fitting::{fit_with_rotation, obb_min_volume};
+use crate::geometry::{linear_part, Aabb3, Transform};
+use crate::matrix::mat3::Mat3;
+use crate::matrix::mat4::Mat4;
+use crate::matrix::square_matrix::SquareMatrix;
+use crate::quaternion::Quaternion;
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector3};
+
+///Oriented bounding box. The columns of `rotation` are the box's local axes in world space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Obb<S: Scalar> {
+    pub center: Vector3<S>,
+    pub half_extents: Vector3<S>,
+    pub rotation: Mat3<S>,
+}
+
+impl<S: Scalar> Obb<S> {
+    pub fn new(center: Vector3<S>, half_extents: Vector3<S>, rotation: Mat3<S>) -> Self {
+        Self { center, half_extents, rotation }
+    }
+
+    pub fn from_quaternion(center: Vector3<S>, half_extents: Vector3<S>, rotation: Quaternion<S>) -> Self {
+        Self::new(center, half_extents, rotation.rotation_matrix())
+    }
+
+    pub fn from_aabb(aabb: &Aabb3<S>) -> Self {
+        Self::new(aabb.center(), aabb.half_extents(), Mat3::IDENTITY)
+    }
+
+    ///The smallest axis-aligned box containing this one.
+    pub fn to_aabb(&self) -> Aabb3<S> {
+        let mut extents: Vector3<S> = Vector::ZERO;
+        for i in 0..3 {
+            for j in 0..3 {
+                extents.0[i] += self.rotation.0[i][j].absolute() * self.half_extents.0[j];
+            }
+        }
+        Aabb3::from_center_half_extents(self.center, extents)
+    }
+
+    pub fn volume(&self) -> S {
+        S::from_f32(8.0) * self.half_extents.0.iter().copied().product::<S>()
+    }
+
+    ///A box containing both, fitted to their corners with [`obb_min_volume`].
+    pub fn merge(&self, other: &Self) -> Self {
+        let mut corners = [Vector::ZERO; 16];
+        corners[..8].copy_from_slice(&self.corners());
+        corners[8..].copy_from_slice(&other.corners());
+        obb_min_volume(&corners).unwrap_or(*self)
+    }
+
+    ///The `i`th local axis in world space.
+    pub fn axis(&self, i: usize) -> Vector3<S> {
+        Vector([self.rotation.0[0][i], self.rotation.0[1][i], self.rotation.0[2][i]])
+    }
+
+    pub fn axes(&self) -> [Vector3<S>; 3] {
+        [self.axis(0), self.axis(1), self.axis(2)]
+    }
+
+    ///World to box coordinates, with the center at the origin.
+    pub fn to_local(&self, point: Vector3<S>) -> Vector3<S> {
+        let offset = point - self.center;
+        Vector(self.axes().map(|axis| axis.dot_product(offset)))
+    }
+
+    pub fn from_local(&self, point: Vector3<S>) -> Vector3<S> {
+        self.center + self.rotation * point
+    }
+
+    ///Boundary points are inside.
+    pub fn contains(&self, point: Vector3<S>) -> bool {
+        let local = self.to_local(point);
+        (0..3).all(|i| local.0[i].absolute() <= self.half_extents.0[i])
+    }
+
+    ///The point of the box closest to `point`, which is `point` itself if it is inside.
+    pub fn closest_point(&self, point: Vector3<S>) -> Vector3<S> {
+        let local = self.to_local(point);
+        self.from_local(local.max(-self.half_extents).min(self.half_extents))
+    }
+
+    pub fn distance(&self, point: Vector3<S>) -> S {
+        self.closest_point(point).distance(point)
+    }
+
+    ///The eight corners, the `i`th on the positive side of the axes whose bit is set in `i`.
+    pub fn corners(&self) -> [Vector3<S>; 8] {
+        std::array::from_fn(|i| {
+            let mut local = -self.half_extents;
+            for axis in 0..3 {
+                if i & (1 << axis) != 0 {
+                    local.0[axis] = self.half_extents.0[axis];
+                }
+            }
+            self.from_local(local)
+        })
+    }
+}
+
+impl<S: Scalar> From<Aabb3<S>> for Obb<S> {
+    fn from(aabb: Aabb3<S>) -> Self {
+        Obb::from_aabb(&aabb)
+    }
+}
+
+impl<S: Scalar> Transform<S> for Obb<S> {
+    ///Under a shear or a non-uniform scale the transformed axes are no longer perpendicular. They are then
+    ///orthonormalized (Gram-Schmidt, in order) and the box grows to contain the transformed corners.
+    fn transformed(&self, m: &Mat4<S>) -> Self {
+        let linear = linear_part(m);
+        let axes = self.axes().map(|a| linear * a);
+        let u = axes[0].normalized();
+        let v = (axes[1] - u * u.dot_product(axes[1])).normalized();
+        let w = u.cross_product(v);
+
+        let mut rotation: Mat3<S> = Mat3::IDENTITY;
+        for (j, axis) in [u, v, w].iter().enumerate() {
+            for i in 0..3 {
+                rotation.0[i][j] = axis.0[i];
+            }
+        }
+        fit_with_rotation(&self.corners().map(|c| m.transform_point(c)), rotation)
+    }
+
+    fn rotated(&self, q: &Quaternion<S>) -> Self {
+        Self::new(q.rotate(self.center), self.half_extents, q.rotation_matrix() * self.rotation)
+    }
+}
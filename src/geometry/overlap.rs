@@ -0,0 +1,142 @@
+use crate::geometry::{tolerance, Aabb3, Obb, Sphere, Triangle};
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector3};
+
+///Penetration of two overlapping shapes: translating the second one by `normal * depth` separates them.
+///Touching shapes overlap with a depth of zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact<S: Scalar> {
+    pub normal: Vector3<S>,
+    pub depth: S,
+}
+
+impl<S: Scalar> Contact<S> {
+    pub fn flipped(&self) -> Self {
+        Self { normal: -self.normal, depth: self.depth }
+    }
+}
+
+pub fn sphere_sphere<S: Scalar>(a: &Sphere<S>, b: &Sphere<S>) -> Option<Contact<S>> {
+    let offset = b.center - a.center;
+    let distance = offset.magnitude();
+    let depth = a.radius + b.radius - distance;
+    if depth < S::ZERO {
+        return None;
+    }
+    //Concentric spheres have no preferred direction
+    let normal = if distance > S::ZERO { offset / distance } else { Vector3::UP };
+    Some(Contact { normal, depth })
+}
+
+pub fn aabb_aabb<S: Scalar>(a: &Aabb3<S>, b: &Aabb3<S>) -> Option<Contact<S>> {
+    separating_axes(&a.corners(), &b.corners(), [Vector3::RIGHT, Vector3::UP, Vector3::FORWARD].map(|axis| (axis, S::ONE)))
+}
+
+pub fn sphere_aabb<S: Scalar>(sphere: &Sphere<S>, aabb: &Aabb3<S>) -> Option<Contact<S>> {
+    let closest = aabb.closest_point(sphere.center);
+    let offset = closest - sphere.center;
+    let distance = offset.magnitude();
+    if distance > sphere.radius {
+        return None;
+    }
+    if distance > S::ZERO {
+        return Some(Contact { normal: offset / distance, depth: sphere.radius - distance });
+    }
+
+    //The center is inside: the sphere leaves through the nearest face, so the box moves the other way
+    let mut best = (S::INFINITY, 0, S::ONE);
+    for i in 0..3 {
+        let to_min = sphere.center.0[i] - aabb.min.0[i];
+        let to_max = aabb.max.0[i] - sphere.center.0[i];
+        if to_min < best.0 {
+            best = (to_min, i, S::ONE);
+        }
+        if to_max < best.0 {
+            best = (to_max, i, -S::ONE);
+        }
+    }
+    let (face, axis, sign) = best;
+    let mut normal: Vector3<S> = Vector::ZERO;
+    normal.0[axis] = sign;
+    Some(Contact { normal, depth: face + sphere.radius })
+}
+
+///Separating axis test over the 3 + 3 face normals and the 9 edge cross products.
+pub fn obb_obb<S: Scalar>(a: &Obb<S>, b: &Obb<S>) -> Option<Contact<S>> {
+    let (axes_a, axes_b) = (a.axes(), b.axes());
+    let mut axes = Vec::with_capacity(15);
+    axes.extend(axes_a.map(|axis| (axis, S::ONE)));
+    axes.extend(axes_b.map(|axis| (axis, S::ONE)));
+    for u in axes_a {
+        for v in axes_b {
+            axes.push((u.cross_product(v), S::ONE));
+        }
+    }
+    separating_axes(&a.corners(), &b.corners(), axes)
+}
+
+///Separating axis test over the box normals, the triangle normal and the 9 cross products of their edges
+///(Akenine-Möller), for voxelization.
+pub fn triangle_aabb<S: Scalar>(triangle: &Triangle<S>, aabb: &Aabb3<S>) -> Option<Contact<S>> {
+    let box_axes = [Vector3::RIGHT, Vector3::UP, Vector3::FORWARD];
+    let edges = [triangle.b - triangle.a, triangle.c - triangle.b, triangle.a - triangle.c];
+    let mut axes = Vec::with_capacity(13);
+    axes.extend(box_axes.map(|axis| (axis, S::ONE)));
+    axes.push((triangle.scaled_normal(), edges[0].magnitude() * edges[2].magnitude()));
+    for e in edges {
+        for u in box_axes {
+            axes.push((e.cross_product(u), e.magnitude()));
+        }
+    }
+    separating_axes(&[triangle.a, triangle.b, triangle.c], &aabb.corners(), axes)
+}
+
+pub fn sphere_triangle<S: Scalar>(sphere: &Sphere<S>, triangle: &Triangle<S>) -> Option<Contact<S>> {
+    let closest = triangle.closest_point(sphere.center);
+    let offset = closest - sphere.center;
+    let distance = offset.magnitude();
+    if distance > sphere.radius {
+        return None;
+    }
+    //A center on the triangle leaves through the front face
+    let normal = if distance > S::ZERO { offset / distance } else { -triangle.normal() };
+    Some(Contact { normal, depth: sphere.radius - distance })
+}
+
+///Projects both convex point sets onto each axis. Returns `None` at the first separating axis, otherwise the
+///axis and direction needing the smallest translation of `b`. Each axis comes with the product of the lengths
+///it was built from, so that degenerate axes, like cross products of parallel edges, are skipped at any scale.
+fn separating_axes<S: Scalar, I: IntoIterator<Item=(Vector3<S>, S)>>(a: &[Vector3<S>], b: &[Vector3<S>], axes: I) -> Option<Contact<S>> {
+    let mut best: Option<Contact<S>> = None;
+    for (axis, scale) in axes {
+        let length = axis.magnitude();
+        if length <= tolerance::<S>() * scale || length == S::ZERO {
+            continue;
+        }
+        let axis = axis / length;
+        let (a_min, a_max) = project(a, axis);
+        let (b_min, b_max) = project(b, axis);
+
+        let forward = a_max - b_min;
+        let backward = b_max - a_min;
+        if forward < S::ZERO || backward < S::ZERO {
+            return None;
+        }
+        let contact = if forward <= backward {
+            Contact { normal: axis, depth: forward }
+        } else {
+            Contact { normal: -axis, depth: backward }
+        };
+        if best.is_none_or(|b| contact.depth < b.depth) {
+            best = Some(contact);
+        }
+    }
+    best
+}
+
+fn project<S: Scalar>(points: &[Vector3<S>], axis: Vector3<S>) -> (S, S) {
+    points.iter().fold((S::INFINITY, -S::INFINITY), |(min, max), p| {
+        let d = p.dot_product(axis);
+        (if d < min { d } else { min }, if d > max { d } else { max })
+    })
+}
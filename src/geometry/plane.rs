@@ -0,0 +1,81 @@
+use crate::geometry::{linear_part, tolerance, Transform};
+use crate::matrix::mat4::Mat4;
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector3};
+
+///The plane of points <i>x</i> with <i>n · x = d</i>. `normal` is kept normalized, so `distance` is the signed
+///distance of the plane from the origin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane<S: Scalar> {
+    pub normal: Vector3<S>,
+    pub distance: S,
+}
+
+impl<S: Scalar> Plane<S> {
+    pub fn new(normal: Vector3<S>, distance: S) -> Self {
+        let length = normal.magnitude();
+        Self { normal: normal / length, distance: distance / length }
+    }
+
+    pub fn from_point_normal(point: Vector3<S>, normal: Vector3<S>) -> Self {
+        let normal = normal.normalized();
+        Self { normal, distance: normal.dot_product(point) }
+    }
+
+    ///The plane through three points, facing the side from which they appear counter-clockwise.
+    ///Returns `None` if the points are collinear, up to [`tolerance`] relative to the triangle's edge lengths.
+    pub fn from_points(a: Vector3<S>, b: Vector3<S>, c: Vector3<S>) -> Option<Self> {
+        let (u, v) = (b - a, c - a);
+        let normal = u.cross_product(v);
+        let length = normal.magnitude();
+        if length <= tolerance::<S>() * u.magnitude() * v.magnitude() || length == S::ZERO {
+            return None;
+        }
+        Some(Self::from_point_normal(a, normal))
+    }
+
+    ///The point of the plane closest to the origin.
+    pub fn point(&self) -> Vector3<S> {
+        self.normal * self.distance
+    }
+
+    ///Positive in front of the plane, the side `normal` points to.
+    pub fn signed_distance(&self, point: Vector3<S>) -> S {
+        self.normal.dot_product(point) - self.distance
+    }
+
+    pub fn closest_point(&self, point: Vector3<S>) -> Vector3<S> {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    pub fn contains(&self, point: Vector3<S>) -> bool {
+        self.signed_distance(point).absolute() <= tolerance()
+    }
+
+    pub fn flipped(&self) -> Self {
+        Self { normal: -self.normal, distance: -self.distance }
+    }
+}
+
+impl<S: Scalar> Transform<S> for Plane<S> {
+    ///Normals are transformed by the inverse transpose, computed here as the cofactor matrix.
+    fn transformed(&self, m: &Mat4<S>) -> Self {
+        let l = linear_part(m);
+        let rows = l.0.map(Vector);
+        let cofactor = Matrix([
+                                  rows[1].cross_product(rows[2]).0,
+                                  rows[2].cross_product(rows[0]).0,
+                                  rows[0].cross_product(rows[1]).0,
+                              ]);
+        let determinant = rows[0].dot_product(rows[1].cross_product(rows[2]));
+        let normal = cofactor * self.normal;
+        let normal = if determinant < S::ZERO { -normal } else { normal };
+        Self::from_point_normal(m.transform_point(self.point()), normal)
+    }
+
+    fn rotated(&self, q: &Quaternion<S>) -> Self {
+        Self { normal: q.rotate(self.normal).normalized(), distance: self.distance }
+    }
+}
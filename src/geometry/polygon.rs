@@ -0,0 +1,329 @@
+use std::cmp::Ordering;
+
+use crate::geometry::{tolerance, Aabb2};
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Winding {
+    CounterClockwise,
+    Clockwise,
+}
+
+///Simple polygon given by its vertices in order, the last one connecting back to the first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon2<S: Scalar> {
+    pub vertices: Vec<Vector2<S>>,
+}
+
+impl<S: Scalar> Polygon2<S> {
+    pub fn new(vertices: Vec<Vector2<S>>) -> Self {
+        Self { vertices }
+    }
+
+    ///The edges as pairs of consecutive vertices, the last one closing the polygon.
+    pub fn edges(&self) -> impl Iterator<Item = (Vector2<S>, Vector2<S>)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    ///Shoelace formula. Positive for counter-clockwise polygons.
+    pub fn signed_area(&self) -> S {
+        self.edges().fold(S::ZERO, |sum, (a, b)| sum + perp_dot(a, b)) / S::from_f32(2.0)
+    }
+
+    pub fn area(&self) -> S {
+        self.signed_area().absolute()
+    }
+
+    ///The center of mass of the enclosed area. `None` for polygons without area.
+    pub fn centroid(&self) -> Option<Vector2<S>> {
+        let area = self.signed_area();
+        if area == S::ZERO {
+            return None;
+        }
+        let sum = self.edges().fold(Vector::ZERO, |sum, (a, b)| sum + (a + b) * perp_dot(a, b));
+        Some(sum / (S::from_f32(6.0) * area))
+    }
+
+    ///`None` for polygons without area.
+    pub fn winding(&self) -> Option<Winding> {
+        let area = self.signed_area();
+        match area.partial_cmp(&S::ZERO) {
+            Some(Ordering::Greater) => Some(Winding::CounterClockwise),
+            Some(Ordering::Less) => Some(Winding::Clockwise),
+            _ => None,
+        }
+    }
+
+    ///The same polygon with the opposite winding.
+    pub fn reversed(&self) -> Self {
+        Self::new(self.vertices.iter().rev().copied().collect())
+    }
+
+    ///Whether every corner turns the same way and the boundary goes around once. Straight corners are allowed.
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        if n < 3 {
+            return false;
+        }
+        let (mut positive, mut negative) = (false, false);
+        let mut turning = S::ZERO;
+        for i in 0..n {
+            let (a, b, c) = (self.vertices[i], self.vertices[(i + 1) % n], self.vertices[(i + 2) % n]);
+            let (u, v) = (b - a, c - b);
+            let turn = perp_dot(u, v);
+            positive |= turn > S::ZERO;
+            negative |= turn < S::ZERO;
+            turning += turn.inv_tangent2(u.dot_product(v));
+        }
+        //A star polygon turns the same way at every corner but goes around more than once
+        let once = S::from_f32(2.0) * S::PI;
+        !(positive && negative) && (turning.absolute() - once).absolute() <= tolerance::<S>()
+    }
+
+    ///How many times the boundary goes counter-clockwise around `point`, negative for clockwise. Points on the
+    ///boundary give an unspecified value.
+    pub fn winding_number(&self, point: Vector2<S>) -> i32 {
+        let mut winding = 0;
+        for (a, b) in self.edges() {
+            let side = perp_dot(b - a, point - a);
+            if a.0[1] <= point.0[1] {
+                if b.0[1] > point.0[1] && side > S::ZERO {
+                    winding += 1;
+                }
+            } else if b.0[1] <= point.0[1] && side < S::ZERO {
+                winding -= 1;
+            }
+        }
+        winding
+    }
+
+    ///Nonzero winding rule, with points within [`tolerance`] of the boundary inside, relative to the diagonal of
+    ///the polygon's bounding box.
+    pub fn contains(&self, point: Vector2<S>) -> bool {
+        let tolerance = Aabb2::from_points(&self.vertices).map_or(S::ZERO, |bounds| tolerance::<S>() * bounds.size().magnitude());
+        self.edges().any(|(a, b)| segment_distance(point, a, b) <= tolerance) || self.winding_number(point) != 0
+    }
+
+    ///Ear clipping, with `holes` inside the polygon cut out through bridges to their rightmost vertices.
+    ///The triangles index the polygon's vertices followed by those of each hole in turn, and are counter-clockwise
+    ///whatever the winding of the input. Holes with fewer than three vertices enclose nothing and are not bridged,
+    ///but their vertices still take up indices. Returns `None` if a hole cannot be bridged to the polygon, e.g.
+    ///because it lies outside it or crosses its boundary. Self-intersecting input otherwise gives a best-effort
+    ///result.
+    pub fn triangulate(&self, holes: &[Polygon2<S>]) -> Option<Vec<[usize; 3]>> {
+        let mut points = self.vertices.clone();
+        let mut ring: Vec<usize> = (0..points.len()).collect();
+        if self.signed_area() < S::ZERO {
+            ring.reverse();
+        }
+
+        //Holes go clockwise, the rightmost first so that its bridge cannot be blocked by those still to come
+        let mut hole_rings = Vec::new();
+        for hole in holes {
+            let start = points.len();
+            points.extend_from_slice(&hole.vertices);
+            if hole.vertices.len() < 3 {
+                continue;
+            }
+            let mut hole_ring: Vec<usize> = (start..points.len()).collect();
+            if hole.signed_area() > S::ZERO {
+                hole_ring.reverse();
+            }
+            hole_rings.push(hole_ring);
+        }
+        let rightmost = |hole: &[usize]| (0..hole.len()).fold(0, |best, i| if points[hole[i]].0[0] > points[hole[best]].0[0] { i } else { best });
+        hole_rings.sort_by(|a, b| points[b[rightmost(b)]].0[0].partial_cmp(&points[a[rightmost(a)]].0[0]).unwrap_or(Ordering::Equal));
+
+        for k in 0..hole_rings.len() {
+            let (hole, rest) = (&hole_rings[k], &hole_rings[k + 1..]);
+            let start = rightmost(hole);
+            let m = points[hole[start]];
+            let mut candidates: Vec<usize> = (0..ring.len()).collect();
+            candidates.sort_by(|i, j| m.distance_squared(points[ring[*i]]).partial_cmp(&m.distance_squared(points[ring[*j]])).unwrap_or(Ordering::Equal));
+            let bridge = candidates.into_iter().find(|j| visible(&points, &ring, hole, rest, m, points[ring[*j]]))?;
+
+            //Around the hole from its rightmost vertex and back, then back across the bridge
+            let mut merged = ring[..=bridge].to_vec();
+            merged.extend_from_slice(&hole[start..]);
+            merged.extend_from_slice(&hole[..=start]);
+            merged.extend_from_slice(&ring[bridge..]);
+            ring = merged;
+        }
+        Some(ear_clip(&points, ring))
+    }
+
+    ///Moves every edge outwards by `distance`, or inwards if it is negative, with mitered corners. Consecutive
+    ///duplicate vertices are dropped. Insetting by more than the polygon's inner radius gives a self-intersecting
+    ///result.
+    pub fn offset(&self, distance: S) -> Self {
+        let mut vertices = self.vertices.clone();
+        vertices.dedup();
+        while vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        let n = vertices.len();
+        if n < 3 {
+            return Self::new(vertices);
+        }
+
+        let outward = if self.signed_area() < S::ZERO { S::NEG_ONE } else { S::ONE };
+        let normal = |a: Vector2<S>, b: Vector2<S>| {
+            let edge = (b - a).normalized();
+            Vector([edge.0[1], -edge.0[0]]) * outward
+        };
+        Self::new(
+            (0..n)
+                .map(|i| {
+                    let (prev, v, next) = (vertices[(i + n - 1) % n], vertices[i], vertices[(i + 1) % n]);
+                    let (n0, n1) = (normal(prev, v), normal(v, next));
+                    let denominator = S::ONE + n0.dot_product(n1);
+                    if denominator <= S::EPSILON {
+                        //The edges fold back onto each other
+                        v + n0 * distance
+                    } else {
+                        v + (n0 + n1) * (distance / denominator)
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    ///Ramer–Douglas–Peucker over the closed boundary: drops vertices while the result stays within `epsilon` of
+    ///the original. Keeps the first vertex and the one farthest from it.
+    pub fn simplify(&self, epsilon: S) -> Self {
+        let n = self.vertices.len();
+        if n <= 3 {
+            return self.clone();
+        }
+        let first = self.vertices[0];
+        let far = (1..n).fold(1, |best, i| if first.distance_squared(self.vertices[i]) > first.distance_squared(self.vertices[best]) { i } else { best });
+
+        let mut vertices = simplify_polyline(&self.vertices[..=far], epsilon);
+        let mut back = self.vertices[far..].to_vec();
+        back.push(first);
+        let back = simplify_polyline(&back, epsilon);
+        vertices.extend_from_slice(&back[1..back.len() - 1]);
+        Self::new(vertices)
+    }
+}
+
+///Ramer–Douglas–Peucker over an open polyline, keeping both ends.
+pub fn simplify_polyline<S: Scalar>(points: &[Vector2<S>], epsilon: S) -> Vec<Vector2<S>> {
+    let n = points.len();
+    if n < 3 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[n - 1] = true;
+    let mut stack = vec![(0, n - 1)];
+    while let Some((start, end)) = stack.pop() {
+        let mut farthest = (start, S::ZERO);
+        for i in start + 1..end {
+            let d = segment_distance(points[i], points[start], points[end]);
+            if d > farthest.1 {
+                farthest = (i, d);
+            }
+        }
+        if farthest.1 > epsilon {
+            keep[farthest.0] = true;
+            stack.push((start, farthest.0));
+            stack.push((farthest.0, end));
+        }
+    }
+    (0..n).filter(|i| keep[*i]).map(|i| points[i]).collect()
+}
+
+///The z component of the cross product, positive when `b` is counter-clockwise from `a`.
+fn perp_dot<S: Scalar>(a: Vector2<S>, b: Vector2<S>) -> S {
+    a.0[0] * b.0[1] - a.0[1] * b.0[0]
+}
+
+fn turn<S: Scalar>(a: Vector2<S>, b: Vector2<S>, c: Vector2<S>) -> S {
+    perp_dot(b - a, c - b)
+}
+
+fn segment_distance<S: Scalar>(point: Vector2<S>, a: Vector2<S>, b: Vector2<S>) -> S {
+    let ab = b - a;
+    let length = ab.magnitude_squared();
+    let t = if length > S::ZERO { (point - a).dot_product(ab) / length } else { S::ZERO };
+    let t = if t < S::ZERO { S::ZERO } else if t > S::ONE { S::ONE } else { t };
+    point.distance(a + ab * t)
+}
+
+///Whether the closed segments touch.
+fn segments_touch<S: Scalar>(p: Vector2<S>, q: Vector2<S>, a: Vector2<S>, b: Vector2<S>) -> bool {
+    let (d1, d2) = (perp_dot(q - p, a - p), perp_dot(q - p, b - p));
+    let (d3, d4) = (perp_dot(b - a, p - a), perp_dot(b - a, q - a));
+    if ((d1 > S::ZERO && d2 < S::ZERO) || (d1 < S::ZERO && d2 > S::ZERO)) && ((d3 > S::ZERO && d4 < S::ZERO) || (d3 < S::ZERO && d4 > S::ZERO)) {
+        return true;
+    }
+    let on = |x: Vector2<S>, (s, t): (Vector2<S>, Vector2<S>)| segment_distance(x, s, t) == S::ZERO;
+    on(a, (p, q)) || on(b, (p, q)) || on(p, (a, b)) || on(q, (a, b))
+}
+
+///Whether the bridge from hole vertex `m` to outer vertex `v` stays inside the polygon without crossing any edge.
+fn visible<S: Scalar>(points: &[Vector2<S>], ring: &[usize], hole: &[usize], rest: &[Vec<usize>], m: Vector2<S>, v: Vector2<S>) -> bool {
+    let rings = std::iter::once(ring).chain(std::iter::once(hole)).chain(rest.iter().map(Vec::as_slice));
+    for r in rings.clone() {
+        for i in 0..r.len() {
+            let (a, b) = (points[r[i]], points[r[(i + 1) % r.len()]]);
+            if [a, b].iter().any(|x| *x == m || *x == v) {
+                continue;
+            }
+            if segments_touch(m, v, a, b) {
+                return false;
+            }
+        }
+    }
+    let middle = m.lerp(v, S::from_f32(0.5));
+    let polygon = |r: &[usize]| Polygon2::new(r.iter().map(|i| points[*i]).collect());
+    let mut rings = rings;
+    rings.next().is_some_and(|outer| polygon(outer).winding_number(middle) != 0) && rings.all(|r| polygon(r).winding_number(middle) == 0)
+}
+
+///Clips convex corners that contain no other vertex from a counter-clockwise ring.
+fn ear_clip<S: Scalar>(points: &[Vector2<S>], mut ring: Vec<usize>) -> Vec<[usize; 3]> {
+    let mut triangles = Vec::new();
+    while ring.len() > 3 {
+        let n = ring.len();
+        let corner = |i: usize| [ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]];
+        let is_ear = |i: usize| {
+            let [a, b, c] = corner(i).map(|j| points[j]);
+            turn(a, b, c) > S::ZERO
+                && ring.iter().all(|j| {
+                    let p = points[*j];
+                    p == a || p == b || p == c || !(turn(a, b, p) >= S::ZERO && turn(b, c, p) >= S::ZERO && turn(c, a, p) >= S::ZERO)
+                })
+        };
+        let flat = |i: usize| {
+            let [a, b, c] = corner(i).map(|j| points[j]);
+            turn(a, b, c) == S::ZERO
+        };
+
+        if let Some(i) = (0..n).find(|i| is_ear(*i)) {
+            triangles.push(corner(i));
+            ring.remove(i);
+        } else if let Some(i) = (0..n).find(|i| flat(*i)) {
+            //Collinear or repeated vertices enclose nothing
+            ring.remove(i);
+        } else if let Some(i) = (0..n).find(|i| {
+            let [a, b, c] = corner(*i).map(|j| points[j]);
+            turn(a, b, c) > S::ZERO
+        }) {
+            //Only reached for self-intersecting input
+            triangles.push(corner(i));
+            ring.remove(i);
+        } else {
+            break;
+        }
+    }
+    if let [a, b, c] = ring[..] {
+        if turn(points[a], points[b], points[c]) > S::ZERO {
+            triangles.push([a, b, c]);
+        }
+    }
+    triangles
+}
@@ -0,0 +1,56 @@
+use crate::geometry::{tolerance, Transform};
+use crate::matrix::mat4::Mat4;
+use crate::quaternion::Quaternion;
+use crate::scalar::Scalar;
+use crate::vector::Vector3;
+
+///A half-line starting at `origin`. `direction` is kept normalized, so parameters are distances. Ray casts also
+///accept a `direction` of any other length set through the field, and then give parameters in units of it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray<S: Scalar> {
+    pub origin: Vector3<S>,
+    pub direction: Vector3<S>,
+}
+
+impl<S: Scalar> Ray<S> {
+    pub fn new(origin: Vector3<S>, direction: Vector3<S>) -> Self {
+        Self { origin, direction: direction.normalized() }
+    }
+
+    ///The ray from `from` through `to`.
+    pub fn through(from: Vector3<S>, to: Vector3<S>) -> Self {
+        Self::new(from, to - from)
+    }
+
+    pub fn at(&self, t: S) -> Vector3<S> {
+        self.origin + self.direction * t
+    }
+
+    ///Parameter of the point on the ray closest to `point`, never negative.
+    pub fn closest_parameter(&self, point: Vector3<S>) -> S {
+        let t = (point - self.origin).dot_product(self.direction);
+        if t < S::ZERO { S::ZERO } else { t }
+    }
+
+    pub fn closest_point(&self, point: Vector3<S>) -> Vector3<S> {
+        self.at(self.closest_parameter(point))
+    }
+
+    pub fn distance(&self, point: Vector3<S>) -> S {
+        self.closest_point(point).distance(point)
+    }
+
+    pub fn contains(&self, point: Vector3<S>) -> bool {
+        self.distance(point) <= tolerance()
+    }
+}
+
+impl<S: Scalar> Transform<S> for Ray<S> {
+    fn transformed(&self, m: &Mat4<S>) -> Self {
+        Self::new(m.transform_point(self.origin), m.transform_vector(self.direction))
+    }
+
+    fn rotated(&self, q: &Quaternion<S>) -> Self {
+        Self::new(q.rotate(self.origin), q.rotate(self.direction))
+    }
+}
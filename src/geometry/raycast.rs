@@ -0,0 +1,244 @@
+use crate::geometry::{Aabb3, Capsule, Obb, Plane, Ray, Sphere, Triangle};
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector3};
+
+///Where a ray first crosses the surface of a shape, at a non-negative `distance` along it.
+///Rays starting inside a solid hit its surface on the way out, with the outward normal. Planes and triangles are
+///two-sided and their normal faces the ray.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit<S: Scalar> {
+    pub distance: S,
+    pub point: Vector3<S>,
+    pub normal: Vector3<S>,
+}
+
+///A [`RayHit`] on a triangle, with the barycentric coordinates of the point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriangleHit<S: Scalar> {
+    pub hit: RayHit<S>,
+    pub barycentric: Vector3<S>,
+}
+
+pub trait RayCast<S: Scalar> {
+    fn raycast(&self, ray: &Ray<S>) -> Option<RayHit<S>>;
+}
+
+impl<S: Scalar> RayHit<S> {
+    fn at(ray: &Ray<S>, distance: S, normal: Vector3<S>) -> Self {
+        Self { distance, point: ray.at(distance), normal }
+    }
+}
+
+impl<S: Scalar> Ray<S> {
+    pub fn cast<T: RayCast<S> + ?Sized>(&self, shape: &T) -> Option<RayHit<S>> {
+        shape.raycast(self)
+    }
+}
+
+///Rays parallel to the plane miss it, even when they lie in it.
+pub fn ray_plane<S: Scalar>(ray: &Ray<S>, plane: &Plane<S>) -> Option<RayHit<S>> {
+    let denominator = plane.normal.dot_product(ray.direction);
+    if denominator.absolute() <= S::EPSILON * ray.direction.magnitude() {
+        return None;
+    }
+    let t = -plane.signed_distance(ray.origin) / denominator;
+    if t < S::ZERO {
+        return None;
+    }
+    let normal = if denominator < S::ZERO { plane.normal } else { -plane.normal };
+    Some(RayHit::at(ray, t, normal))
+}
+
+///A ray touching the sphere tangentially hits it once.
+pub fn ray_sphere<S: Scalar>(ray: &Ray<S>, sphere: &Sphere<S>) -> Option<RayHit<S>> {
+    let offset = ray.origin - sphere.center;
+    let a = ray.direction.magnitude_squared();
+    let b = offset.dot_product(ray.direction);
+    let c = offset.magnitude_squared() - sphere.radius * sphere.radius;
+    if (c > S::ZERO && b > S::ZERO) || a == S::ZERO {
+        return None;
+    }
+    let discriminant = b * b - a * c;
+    if discriminant < S::ZERO {
+        return None;
+    }
+    let root = discriminant.square_root();
+    let t = if c > S::ZERO { (-b - root) / a } else { (-b + root) / a };
+    let point = ray.at(t);
+    Some(RayHit { distance: t, point, normal: (point - sphere.center) / sphere.radius })
+}
+
+///Slab method. Rays running along a face are inside that slab and hit the box.
+pub fn ray_aabb<S: Scalar>(ray: &Ray<S>, aabb: &Aabb3<S>) -> Option<RayHit<S>> {
+    let mut near = -S::INFINITY;
+    let mut far = S::INFINITY;
+    let (mut near_axis, mut far_axis) = (None, None);
+
+    for i in 0..3 {
+        let (o, d) = (ray.origin.0[i], ray.direction.0[i]);
+        if d == S::ZERO {
+            if o < aabb.min.0[i] || o > aabb.max.0[i] {
+                return None;
+            }
+            continue;
+        }
+        let (t0, t1) = ((aabb.min.0[i] - o) / d, (aabb.max.0[i] - o) / d);
+        let (t0, t1) = if t0 < t1 { (t0, t1) } else { (t1, t0) };
+        if t0 > near {
+            near = t0;
+            near_axis = Some(i);
+        }
+        if t1 < far {
+            far = t1;
+            far_axis = Some(i);
+        }
+        if near > far || far < S::ZERO {
+            return None;
+        }
+    }
+
+    //The normal opposes the direction when entering and follows it when leaving
+    let (t, axis, sign) = if near >= S::ZERO {
+        (near, near_axis, -S::ONE)
+    } else {
+        (far, far_axis, S::ONE)
+    };
+    //Only a ray with no non-zero component gets here without an axis
+    let axis = axis?;
+    let mut normal: Vector3<S> = Vector::ZERO;
+    normal.0[axis] = if ray.direction.0[axis] > S::ZERO { sign } else { -sign };
+    Some(RayHit::at(ray, t, normal))
+}
+
+///Möller–Trumbore. Rays in the triangle's plane miss it.
+pub fn ray_triangle<S: Scalar>(ray: &Ray<S>, triangle: &Triangle<S>) -> Option<TriangleHit<S>> {
+    let e1 = triangle.b - triangle.a;
+    let e2 = triangle.c - triangle.a;
+    let p = ray.direction.cross_product(e2);
+    let determinant = e1.dot_product(p);
+    if determinant.absolute() <= S::EPSILON * e1.magnitude() * e2.magnitude() {
+        return None;
+    }
+    let inverse = S::ONE / determinant;
+
+    let s = ray.origin - triangle.a;
+    let u = s.dot_product(p) * inverse;
+    if u < S::ZERO || u > S::ONE {
+        return None;
+    }
+    let q = s.cross_product(e1);
+    let v = ray.direction.dot_product(q) * inverse;
+    if v < S::ZERO || u + v > S::ONE {
+        return None;
+    }
+    let t = e2.dot_product(q) * inverse;
+    if t < S::ZERO {
+        return None;
+    }
+
+    let normal = e1.cross_product(e2).normalized();
+    let normal = if normal.dot_product(ray.direction) > S::ZERO { -normal } else { normal };
+    Some(TriangleHit {
+        hit: RayHit::at(ray, t, normal),
+        barycentric: Vector([S::ONE - u - v, u, v]),
+    })
+}
+
+///The slab method in the box's frame.
+pub fn ray_obb<S: Scalar>(ray: &Ray<S>, obb: &Obb<S>) -> Option<RayHit<S>> {
+    let local = Ray {
+        origin: obb.to_local(ray.origin),
+        direction: Vector(obb.axes().map(|axis| axis.dot_product(ray.direction))),
+    };
+    let hit = ray_aabb(&local, &Aabb3::from_center_half_extents(Vector::ZERO, obb.half_extents))?;
+    Some(RayHit::at(ray, hit.distance, obb.rotation * hit.normal))
+}
+
+pub fn ray_capsule<S: Scalar>(ray: &Ray<S>, capsule: &Capsule<S>) -> Option<RayHit<S>> {
+    if !capsule.contains(ray.origin) {
+        return capsule_entry(ray, capsule);
+    }
+    //From inside, enter from the far side along the reversed ray; its entry point is our exit.
+    let segment = &capsule.segment;
+    let span = ray.origin.distance(segment.a) + segment.length() + capsule.radius * S::from_f32(2.0);
+    let reversed = Ray { origin: ray.at(span), direction: -ray.direction };
+    let hit = capsule_entry(&reversed, capsule)?;
+    Some(RayHit::at(ray, span - hit.distance, hit.normal))
+}
+
+///The first hit of a ray starting outside the capsule: the nearest of the cylinder wall and the two end caps.
+fn capsule_entry<S: Scalar>(ray: &Ray<S>, capsule: &Capsule<S>) -> Option<RayHit<S>> {
+    let (a, b, r) = (capsule.segment.a, capsule.segment.b, capsule.radius);
+    let mut best: Option<RayHit<S>> = None;
+    let mut consider = |hit: RayHit<S>| {
+        if best.is_none_or(|b| hit.distance < b.distance) {
+            best = Some(hit);
+        }
+    };
+
+    let length = capsule.segment.length();
+    if length > S::ZERO {
+        let axis = (b - a) / length;
+        let oa = ray.origin - a;
+        let d = ray.direction - axis * ray.direction.dot_product(axis);
+        let o = oa - axis * oa.dot_product(axis);
+        let qa = d.magnitude_squared();
+        //Rays along the axis never cross the side
+        if qa > S::EPSILON * ray.direction.magnitude_squared() {
+            let qb = o.dot_product(d);
+            let qc = o.magnitude_squared() - r * r;
+            let discriminant = qb * qb - qa * qc;
+            if discriminant >= S::ZERO {
+                let t = (-qb - discriminant.square_root()) / qa;
+                let y = (oa + ray.direction * t).dot_product(axis);
+                if t >= S::ZERO && y >= S::ZERO && y <= length {
+                    let point = ray.at(t);
+                    consider(RayHit { distance: t, point, normal: (point - (a + axis * y)) / r });
+                }
+            }
+        }
+    }
+
+    for center in [a, b] {
+        if let Some(hit) = ray_sphere(ray, &Sphere::new(center, r)) {
+            consider(hit);
+        }
+    }
+    best
+}
+
+impl<S: Scalar> RayCast<S> for Plane<S> {
+    fn raycast(&self, ray: &Ray<S>) -> Option<RayHit<S>> {
+        ray_plane(ray, self)
+    }
+}
+
+impl<S: Scalar> RayCast<S> for Sphere<S> {
+    fn raycast(&self, ray: &Ray<S>) -> Option<RayHit<S>> {
+        ray_sphere(ray, self)
+    }
+}
+
+impl<S: Scalar> RayCast<S> for Aabb3<S> {
+    fn raycast(&self, ray: &Ray<S>) -> Option<RayHit<S>> {
+        ray_aabb(ray, self)
+    }
+}
+
+impl<S: Scalar> RayCast<S> for Triangle<S> {
+    fn raycast(&self, ray: &Ray<S>) -> Option<RayHit<S>> {
+        ray_triangle(ray, self).map(|h| h.hit)
+    }
+}
+
+impl<S: Scalar> RayCast<S> for Obb<S> {
+    fn raycast(&self, ray: &Ray<S>) -> Option<RayHit<S>> {
+        ray_obb(ray, self)
+    }
+}
+
+impl<S: Scalar> RayCast<S> for Capsule<S> {
+    fn raycast(&self, ray: &Ray<S>) -> Option<RayHit<S>> {
+        ray_capsule(ray, self)
+    }
+}
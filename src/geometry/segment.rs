@@ -0,0 +1,106 @@
+use crate::geometry::{tolerance, Transform};
+use crate::matrix::mat4::Mat4;
+use crate::quaternion::Quaternion;
+use crate::scalar::Scalar;
+use crate::vector::Vector3;
+
+///The line segment between `a` and `b`, parametrized from `a` at 0 to `b` at 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment<S: Scalar> {
+    pub a: Vector3<S>,
+    pub b: Vector3<S>,
+}
+
+impl<S: Scalar> Segment<S> {
+    pub fn new(a: Vector3<S>, b: Vector3<S>) -> Self {
+        Self { a, b }
+    }
+
+    pub fn length(&self) -> S {
+        self.a.distance(self.b)
+    }
+
+    pub fn at(&self, t: S) -> Vector3<S> {
+        self.a.lerp(self.b, t)
+    }
+
+    ///Parameter of the point on the segment closest to `point`, in `[0, 1]`.
+    pub fn closest_parameter(&self, point: Vector3<S>) -> S {
+        let ab = self.b - self.a;
+        let length = ab.magnitude_squared();
+        if length <= S::ZERO {
+            return S::ZERO;
+        }
+        clamp_unit((point - self.a).dot_product(ab) / length)
+    }
+
+    pub fn closest_point(&self, point: Vector3<S>) -> Vector3<S> {
+        self.at(self.closest_parameter(point))
+    }
+
+    pub fn distance(&self, point: Vector3<S>) -> S {
+        self.closest_point(point).distance(point)
+    }
+
+    pub fn contains(&self, point: Vector3<S>) -> bool {
+        self.distance(point) <= tolerance()
+    }
+
+    ///The closest pair of points, the first on `self` and the second on `other` (Ericson, 5.1.9).
+    pub fn closest_points(&self, other: &Segment<S>) -> (Vector3<S>, Vector3<S>) {
+        let d1 = self.b - self.a;
+        let d2 = other.b - other.a;
+        let r = self.a - other.a;
+        let a = d1.magnitude_squared();
+        let e = d2.magnitude_squared();
+        let f = d2.dot_product(r);
+        //Squared lengths, so a segment is a point when it is within tolerance of the longer one's length
+        let point = S::EPSILON * (a + e);
+
+        let (s, t) = if a <= point && e <= point {
+            (S::ZERO, S::ZERO)
+        } else if a <= point {
+            (S::ZERO, clamp_unit(f / e))
+        } else {
+            let c = d1.dot_product(r);
+            if e <= point {
+                (clamp_unit(-c / a), S::ZERO)
+            } else {
+                let b = d1.dot_product(d2);
+                let denominator = a * e - b * b;
+                //Parallel segments have no unique pair, any s works
+                let s = if denominator > S::ZERO { clamp_unit((b * f - c * e) / denominator) } else { S::ZERO };
+                let t = (b * s + f) / e;
+                if t < S::ZERO {
+                    (clamp_unit(-c / a), S::ZERO)
+                } else if t > S::ONE {
+                    (clamp_unit((b - c) / a), S::ONE)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+
+        (self.at(s), other.at(t))
+    }
+}
+
+fn clamp_unit<S: Scalar>(t: S) -> S {
+    if t < S::ZERO {
+        S::ZERO
+    } else if t > S::ONE {
+        S::ONE
+    } else {
+        t
+    }
+}
+
+impl<S: Scalar> Transform<S> for Segment<S> {
+    fn transformed(&self, m: &Mat4<S>) -> Self {
+        Self::new(m.transform_point(self.a), m.transform_point(self.b))
+    }
+
+    fn rotated(&self, q: &Quaternion<S>) -> Self {
+        Self::new(q.rotate(self.a), q.rotate(self.b))
+    }
+}
@@ -0,0 +1,52 @@
+use crate::geometry::{max_scale, Transform};
+use crate::matrix::mat4::Mat4;
+use crate::quaternion::Quaternion;
+use crate::scalar::Scalar;
+use crate::vector::Vector3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere<S: Scalar> {
+    pub center: Vector3<S>,
+    pub radius: S,
+}
+
+impl<S: Scalar> Sphere<S> {
+    pub fn new(center: Vector3<S>, radius: S) -> Self {
+        Self { center, radius }
+    }
+
+    pub fn contains(&self, point: Vector3<S>) -> bool {
+        self.center.distance_squared(point) <= self.radius * self.radius
+    }
+
+    pub fn contains_sphere(&self, other: &Sphere<S>) -> bool {
+        self.center.distance(other.center) + other.radius <= self.radius
+    }
+
+    ///The point of the ball closest to `point`, which is `point` itself if it is inside.
+    pub fn closest_point(&self, point: Vector3<S>) -> Vector3<S> {
+        let offset = point - self.center;
+        let distance = offset.magnitude();
+        if distance <= self.radius {
+            point
+        } else {
+            self.center + offset * (self.radius / distance)
+        }
+    }
+
+    ///Distance from `point` to the ball, zero inside it.
+    pub fn distance(&self, point: Vector3<S>) -> S {
+        let d = self.center.distance(point) - self.radius;
+        if d > S::ZERO { d } else { S::ZERO }
+    }
+}
+
+impl<S: Scalar> Transform<S> for Sphere<S> {
+    fn transformed(&self, m: &Mat4<S>) -> Self {
+        Self::new(m.transform_point(self.center), self.radius * max_scale(m))
+    }
+
+    fn rotated(&self, q: &Quaternion<S>) -> Self {
+        Self::new(q.rotate(self.center), self.radius)
+    }
+}
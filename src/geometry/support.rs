@@ -0,0 +1,102 @@
+use crate::geometry::{Aabb3, Capsule, Obb, Segment, Sphere, Triangle};
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector3};
+
+///A convex shape described by its support function, as used by [`gjk`](crate::geometry::gjk).
+pub trait SupportMap<S: Scalar> {
+    ///The point of the shape farthest in `direction`, which need not be normalized. Any of several equally far
+    ///points will do.
+    fn support(&self, direction: Vector3<S>) -> Vector3<S>;
+}
+
+///The Minkowski sum of two shapes, e.g. a box and a sphere for a rounded box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinkowskiSum<A, B>(pub A, pub B);
+
+impl<S: Scalar, T: SupportMap<S> + ?Sized> SupportMap<S> for &T {
+    fn support(&self, direction: Vector3<S>) -> Vector3<S> {
+        (**self).support(direction)
+    }
+}
+
+impl<S: Scalar, A: SupportMap<S>, B: SupportMap<S>> SupportMap<S> for MinkowskiSum<A, B> {
+    fn support(&self, direction: Vector3<S>) -> Vector3<S> {
+        self.0.support(direction) + self.1.support(direction)
+    }
+}
+
+impl<S: Scalar> SupportMap<S> for Sphere<S> {
+    fn support(&self, direction: Vector3<S>) -> Vector3<S> {
+        let length = direction.magnitude();
+        if length > S::ZERO {
+            self.center + direction * (self.radius / length)
+        } else {
+            self.center + Vector3::RIGHT * self.radius
+        }
+    }
+}
+
+impl<S: Scalar> SupportMap<S> for Aabb3<S> {
+    fn support(&self, direction: Vector3<S>) -> Vector3<S> {
+        let mut out = self.min;
+        for i in 0..3 {
+            if direction.0[i] >= S::ZERO {
+                out.0[i] = self.max.0[i];
+            }
+        }
+        out
+    }
+}
+
+impl<S: Scalar> SupportMap<S> for Obb<S> {
+    fn support(&self, direction: Vector3<S>) -> Vector3<S> {
+        let mut out = self.center;
+        for (i, axis) in self.axes().into_iter().enumerate() {
+            let extent = self.half_extents.0[i];
+            out += if axis.dot_product(direction) >= S::ZERO { axis * extent } else { axis * -extent };
+        }
+        out
+    }
+}
+
+impl<S: Scalar> SupportMap<S> for Capsule<S> {
+    fn support(&self, direction: Vector3<S>) -> Vector3<S> {
+        self.segment.support(direction) + Sphere::new(Vector::ZERO, self.radius).support(direction)
+    }
+}
+
+impl<S: Scalar> SupportMap<S> for Segment<S> {
+    fn support(&self, direction: Vector3<S>) -> Vector3<S> {
+        farthest(&[self.a, self.b], direction)
+    }
+}
+
+impl<S: Scalar> SupportMap<S> for Triangle<S> {
+    fn support(&self, direction: Vector3<S>) -> Vector3<S> {
+        farthest(&[self.a, self.b, self.c], direction)
+    }
+}
+
+///The convex hull of the points. An empty slice is treated as the origin.
+impl<S: Scalar> SupportMap<S> for [Vector3<S>] {
+    fn support(&self, direction: Vector3<S>) -> Vector3<S> {
+        farthest(self, direction)
+    }
+}
+
+impl<S: Scalar> SupportMap<S> for Vec<Vector3<S>> {
+    fn support(&self, direction: Vector3<S>) -> Vector3<S> {
+        farthest(self, direction)
+    }
+}
+
+fn farthest<S: Scalar>(points: &[Vector3<S>], direction: Vector3<S>) -> Vector3<S> {
+    let mut best = (Vector::ZERO, -S::INFINITY);
+    for p in points {
+        let d = p.dot_product(direction);
+        if d > best.1 {
+            best = (*p, d);
+        }
+    }
+    best.0
+}
@@ -0,0 +1,113 @@
+use crate::geometry::{tolerance, Plane, Transform};
+use crate::matrix::mat4::Mat4;
+use crate::quaternion::Quaternion;
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector3};
+
+///A triangle, front-facing where `a`, `b`, `c` appear counter-clockwise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle<S: Scalar> {
+    pub a: Vector3<S>,
+    pub b: Vector3<S>,
+    pub c: Vector3<S>,
+}
+
+impl<S: Scalar> Triangle<S> {
+    pub fn new(a: Vector3<S>, b: Vector3<S>, c: Vector3<S>) -> Self {
+        Self { a, b, c }
+    }
+
+    ///Unnormalized normal, its length is twice the area.
+    pub fn scaled_normal(&self) -> Vector3<S> {
+        (self.b - self.a).cross_product(self.c - self.a)
+    }
+
+    pub fn normal(&self) -> Vector3<S> {
+        self.scaled_normal().normalized()
+    }
+
+    pub fn area(&self) -> S {
+        self.scaled_normal().magnitude() / S::from_f32(2.0)
+    }
+
+    pub fn centroid(&self) -> Vector3<S> {
+        (self.a + self.b + self.c) / S::from_f32(3.0)
+    }
+
+    ///`None` for degenerate triangles.
+    pub fn plane(&self) -> Option<Plane<S>> {
+        Plane::from_points(self.a, self.b, self.c)
+    }
+
+    ///Barycentric coordinates `(u, v, w)` of `point` projected onto the triangle's plane, so that it equals
+    ///`u * a + v * b + w * c`.
+    pub fn barycentric(&self, point: Vector3<S>) -> Vector3<S> {
+        let (v0, v1, v2) = (self.b - self.a, self.c - self.a, point - self.a);
+        let (d00, d01, d11) = (v0.dot_product(v0), v0.dot_product(v1), v1.dot_product(v1));
+        let (d20, d21) = (v2.dot_product(v0), v2.dot_product(v1));
+        let denominator = d00 * d11 - d01 * d01;
+        let v = (d11 * d20 - d01 * d21) / denominator;
+        let w = (d00 * d21 - d01 * d20) / denominator;
+        Vector([S::ONE - v - w, v, w])
+    }
+
+    ///The point of the triangle closest to `point`, found by its Voronoi region (Ericson, 5.1.5).
+    pub fn closest_point(&self, point: Vector3<S>) -> Vector3<S> {
+        let (a, b, c) = (self.a, self.b, self.c);
+        let zero = S::ZERO;
+        let (ab, ac, ap) = (b - a, c - a, point - a);
+
+        let (d1, d2) = (ab.dot_product(ap), ac.dot_product(ap));
+        if d1 <= zero && d2 <= zero {
+            return a;
+        }
+
+        let bp = point - b;
+        let (d3, d4) = (ab.dot_product(bp), ac.dot_product(bp));
+        if d3 >= zero && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= zero && d1 >= zero && d3 <= zero {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - c;
+        let (d5, d6) = (ab.dot_product(cp), ac.dot_product(cp));
+        if d6 >= zero && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= zero && d2 >= zero && d6 <= zero {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denominator = S::ONE / (va + vb + vc);
+        a + ab * (vb * denominator) + ac * (vc * denominator)
+    }
+
+    pub fn distance(&self, point: Vector3<S>) -> S {
+        self.closest_point(point).distance(point)
+    }
+
+    pub fn contains(&self, point: Vector3<S>) -> bool {
+        self.distance(point) <= tolerance()
+    }
+}
+
+impl<S: Scalar> Transform<S> for Triangle<S> {
+    fn transformed(&self, m: &Mat4<S>) -> Self {
+        Self::new(m.transform_point(self.a), m.transform_point(self.b), m.transform_point(self.c))
+    }
+
+    fn rotated(&self, q: &Quaternion<S>) -> Self {
+        Self::new(q.rotate(self.a), q.rotate(self.b), q.rotate(self.c))
+    }
+}
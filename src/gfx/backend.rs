@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+use std::ffi::{CString, NulError};
+
+///The GL calls the crate needs to set uniforms. [`GlBackend`] forwards to the loaded OpenGL functions and
+///[`RecordingBackend`] keeps everything in memory so bindings can be checked without a GL context.
+///
+///`data` always holds `count * components` values (`count * dimension²` for matrices).
+pub trait UniformBackend {
+    fn uniform_location(&mut self, program: u32, name: &str) -> Result<i32, NulError>;
+
+    fn uniform_fv(&mut self, location: i32, components: usize, data: &[f32]);
+    fn uniform_dv(&mut self, location: i32, components: usize, data: &[f64]);
+    fn uniform_iv(&mut self, location: i32, components: usize, data: &[i32]);
+    fn uniform_uiv(&mut self, location: i32, components: usize, data: &[u32]);
+
+    fn uniform_matrix_fv(&mut self, location: i32, dimension: usize, transpose: bool, data: &[f32]);
+    fn uniform_matrix_dv(&mut self, location: i32, dimension: usize, transpose: bool, data: &[f64]);
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GlBackend;
+
+fn gl_bool(b: bool) -> gl::types::GLboolean {
+    if b { gl::TRUE } else { gl::FALSE }
+}
+
+impl UniformBackend for GlBackend {
+    fn uniform_location(&mut self, program: u32, name: &str) -> Result<i32, NulError> {
+        let name = CString::new(name)?;
+        unsafe {
+            Ok(gl::GetUniformLocation(
+                program,
+                name.as_ptr() as *const _,
+            ))
+        }
+    }
+
+    fn uniform_fv(&mut self, location: i32, components: usize, data: &[f32]) {
+        let count = (data.len() / components) as i32;
+        unsafe {
+            match components {
+                1 => gl::Uniform1fv(location, count, data.as_ptr()),
+                2 => gl::Uniform2fv(location, count, data.as_ptr()),
+                3 => gl::Uniform3fv(location, count, data.as_ptr()),
+                4 => gl::Uniform4fv(location, count, data.as_ptr()),
+                _ => panic!("unsupported uniform component count {}", components),
+            }
+        }
+    }
+
+    fn uniform_dv(&mut self, location: i32, components: usize, data: &[f64]) {
+        let count = (data.len() / components) as i32;
+        unsafe {
+            match components {
+                1 => gl::Uniform1dv(location, count, data.as_ptr()),
+                2 => gl::Uniform2dv(location, count, data.as_ptr()),
+                3 => gl::Uniform3dv(location, count, data.as_ptr()),
+                4 => gl::Uniform4dv(location, count, data.as_ptr()),
+                _ => panic!("unsupported uniform component count {}", components),
+            }
+        }
+    }
+
+    fn uniform_iv(&mut self, location: i32, components: usize, data: &[i32]) {
+        let count = (data.len() / components) as i32;
+        unsafe {
+            match components {
+                1 => gl::Uniform1iv(location, count, data.as_ptr()),
+                2 => gl::Uniform2iv(location, count, data.as_ptr()),
+                3 => gl::Uniform3iv(location, count, data.as_ptr()),
+                4 => gl::Uniform4iv(location, count, data.as_ptr()),
+                _ => panic!("unsupported uniform component count {}", components),
+            }
+        }
+    }
+
+    fn uniform_uiv(&mut self, location: i32, components: usize, data: &[u32]) {
+        let count = (data.len() / components) as i32;
+        unsafe {
+            match components {
+                1 => gl::Uniform1uiv(location, count, data.as_ptr()),
+                2 => gl::Uniform2uiv(location, count, data.as_ptr()),
+                3 => gl::Uniform3uiv(location, count, data.as_ptr()),
+                4 => gl::Uniform4uiv(location, count, data.as_ptr()),
+                _ => panic!("unsupported uniform component count {}", components),
+            }
+        }
+    }
+
+    fn uniform_matrix_fv(&mut self, location: i32, dimension: usize, transpose: bool, data: &[f32]) {
+        let count = (data.len() / (dimension * dimension)) as i32;
+        unsafe {
+            match dimension {
+                2 => gl::UniformMatrix2fv(location, count, gl_bool(transpose), data.as_ptr()),
+                3 => gl::UniformMatrix3fv(location, count, gl_bool(transpose), data.as_ptr()),
+                4 => gl::UniformMatrix4fv(location, count, gl_bool(transpose), data.as_ptr()),
+                _ => panic!("unsupported uniform matrix dimension {}", dimension),
+            }
+        }
+    }
+
+    fn uniform_matrix_dv(&mut self, location: i32, dimension: usize, transpose: bool, data: &[f64]) {
+        let count = (data.len() / (dimension * dimension)) as i32;
+        unsafe {
+            match dimension {
+                2 => gl::UniformMatrix2dv(location, count, gl_bool(transpose), data.as_ptr()),
+                3 => gl::UniformMatrix3dv(location, count, gl_bool(transpose), data.as_ptr()),
+                4 => gl::UniformMatrix4dv(location, count, gl_bool(transpose), data.as_ptr()),
+                _ => panic!("unsupported uniform matrix dimension {}", dimension),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UniformData {
+    Float(Vec<f32>),
+    Double(Vec<f64>),
+    Int(Vec<i32>),
+    UInt(Vec<u32>),
+}
+
+///A single recorded `glUniform*` call. `components` is the matrix dimension for matrix uploads.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UniformCall {
+    pub location: i32,
+    pub components: usize,
+    pub matrix: bool,
+    pub transpose: bool,
+    pub data: UniformData,
+}
+
+///An in-memory [`UniformBackend`]. Uniforms must be declared per program up front; looking up any other name
+///returns `-1` and, as in GL, uploads to location `-1` are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct RecordingBackend {
+    locations: HashMap<(u32, String), i32>,
+    lookups: usize,
+    pub calls: Vec<UniformCall>,
+}
+
+impl RecordingBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn declare(&mut self, program: u32, name: &str) -> i32 {
+        let next = self.locations.len() as i32;
+        *self.locations.entry((program, name.to_string())).or_insert(next)
+    }
+
+    pub fn location(&self, program: u32, name: &str) -> Option<i32> {
+        self.locations.get(&(program, name.to_string())).copied()
+    }
+
+    ///How many times [`UniformBackend::uniform_location`] was called.
+    pub fn lookups(&self) -> usize {
+        self.lookups
+    }
+
+    ///The most recent upload to the named uniform, if any.
+    pub fn last(&self, program: u32, name: &str) -> Option<&UniformCall> {
+        let location = self.location(program, name)?;
+        self.calls.iter().rev().find(|c| c.location == location)
+    }
+
+    fn record(&mut self, location: i32, components: usize, matrix: bool, transpose: bool, data: UniformData) {
+        if location == -1 {
+            return;
+        }
+        self.calls.push(UniformCall {
+            location,
+            components,
+            matrix,
+            transpose,
+            data,
+        });
+    }
+}
+
+impl UniformBackend for RecordingBackend {
+    fn uniform_location(&mut self, program: u32, name: &str) -> Result<i32, NulError> {
+        self.lookups += 1;
+        CString::new(name)?;
+        Ok(self.location(program, name).unwrap_or(-1))
+    }
+
+    fn uniform_fv(&mut self, location: i32, components: usize, data: &[f32]) {
+        self.record(location, components, false, false, UniformData::Float(data.to_vec()));
+    }
+
+    fn uniform_dv(&mut self, location: i32, components: usize, data: &[f64]) {
+        self.record(location, components, false, false, UniformData::Double(data.to_vec()));
+    }
+
+    fn uniform_iv(&mut self, location: i32, components: usize, data: &[i32]) {
+        self.record(location, components, false, false, UniformData::Int(data.to_vec()));
+    }
+
+    fn uniform_uiv(&mut self, location: i32, components: usize, data: &[u32]) {
+        self.record(location, components, false, false, UniformData::UInt(data.to_vec()));
+    }
+
+    fn uniform_matrix_fv(&mut self, location: i32, dimension: usize, transpose: bool, data: &[f32]) {
+        self.record(location, dimension, true, transpose, UniformData::Float(data.to_vec()));
+    }
+
+    fn uniform_matrix_dv(&mut self, location: i32, dimension: usize, transpose: bool, data: &[f64]) {
+        self.record(location, dimension, true, transpose, UniformData::Double(data.to_vec()));
+    }
+}
@@ -1,16 +1,147 @@
-use std::ffi::CString;
+use std::ffi::NulError;
 
-use crate::pointer::Pointer;
+use crate::angle::Angle;
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector;
+use crate::vertex::{Vertex, VertexAttribute};
 
-pub trait GfxBind: Pointer {
-    fn uniform_location(&self, name: &dyn ToString, program: u32) -> Result<i32, std::ffi::NulError> {
+pub mod backend;
+pub mod uniform;
+
+use backend::{GlBackend, UniformBackend};
+
+pub trait GfxBind {
+    fn uniform_location(&self, name: &dyn ToString, program: u32) -> Result<i32, NulError> {
+        GlBackend.uniform_location(program, &name.to_string())
+    }
+
+    fn bind(&self, name: &dyn ToString, program: u32) -> Result<(), NulError> {
+        self.bind_with(&mut GlBackend, name, program)
+    }
+
+    ///Looks up `name` in `program` and uploads the value through `backend`.
+    fn bind_with(&self, backend: &mut dyn UniformBackend, name: &dyn ToString, program: u32) -> Result<(), NulError> {
+        let loc = backend.uniform_location(program, &name.to_string())?;
+        self.upload(backend, loc);
+        Ok(())
+    }
+
+    ///Uploads the value to an already resolved uniform location.
+    fn upload(&self, backend: &mut dyn UniformBackend, location: i32);
+}
+
+///Implements [`GfxBind`] for a vector type and for slices of it (bound as a uniform array).
+macro_rules! gfx_bind_vector {
+    ($($l:literal, $s:ty => $f:ident),*) => {
+        $(
+            impl GfxBind for Vector<$l, $s> {
+                fn upload(&self, backend: &mut dyn UniformBackend, location: i32) {
+                    backend.$f(location, $l, &self.0);
+                }
+            }
+
+            impl GfxBind for [Vector<$l, $s>] {
+                fn upload(&self, backend: &mut dyn UniformBackend, location: i32) {
+                    backend.$f(location, $l, crate::pointer::flatten(self));
+                }
+            }
+        )*
+    };
+}
+
+///Implements [`GfxBind`] for a matrix type and for slices of it (bound as a uniform array).
+macro_rules! gfx_bind_matrix {
+    ($($l:literal, $s:ty => $f:ident),*) => {
+        $(
+            impl GfxBind for Matrix<$l, $l, $s> {
+                fn upload(&self, backend: &mut dyn UniformBackend, location: i32) {
+                    backend.$f(location, $l, false, self.0.as_flattened());
+                }
+            }
+
+            impl GfxBind for [Matrix<$l, $l, $s>] {
+                fn upload(&self, backend: &mut dyn UniformBackend, location: i32) {
+                    backend.$f(location, $l, false, crate::pointer::flatten_matrices(self));
+                }
+            }
+        )*
+    };
+}
+
+///Quaternions are bound as a `vec4` in their `(x, y, z, w)` memory order and angles as a `float` in radians.
+macro_rules! gfx_bind_rotation {
+    ($($s:ty => $f:ident),*) => {
+        $(
+            impl GfxBind for Quaternion<$s> {
+                fn upload(&self, backend: &mut dyn UniformBackend, location: i32) {
+                    backend.$f(location, 4, self.as_slice());
+                }
+            }
+
+            impl GfxBind for [Quaternion<$s>] {
+                fn upload(&self, backend: &mut dyn UniformBackend, location: i32) {
+                    backend.$f(location, 4, crate::pointer::flatten_quaternions(self));
+                }
+            }
+
+            impl GfxBind for Angle<$s> {
+                fn upload(&self, backend: &mut dyn UniformBackend, location: i32) {
+                    backend.$f(location, 1, &[self.to_radians().to_inner()]);
+                }
+            }
+        )*
+    };
+}
+
+gfx_bind_vector!(
+    2, f32 => uniform_fv,
+    3, f32 => uniform_fv,
+    4, f32 => uniform_fv,
+    2, f64 => uniform_dv,
+    3, f64 => uniform_dv,
+    4, f64 => uniform_dv,
+    2, i32 => uniform_iv,
+    3, i32 => uniform_iv,
+    4, i32 => uniform_iv,
+    2, u32 => uniform_uiv,
+    3, u32 => uniform_uiv,
+    4, u32 => uniform_uiv
+);
+
+gfx_bind_matrix!(
+    2, f32 => uniform_matrix_fv,
+    3, f32 => uniform_matrix_fv,
+    4, f32 => uniform_matrix_fv,
+    2, f64 => uniform_matrix_dv,
+    3, f64 => uniform_matrix_dv,
+    4, f64 => uniform_matrix_dv
+);
+
+gfx_bind_rotation!(
+    f32 => uniform_fv,
+    f64 => uniform_dv
+);
+
+///Applies the attributes of `V` to the currently bound vertex array object and vertex buffer.
+///Integer attributes that are not normalized use `glVertexAttribIPointer` and doubles `glVertexAttribLPointer`.
+pub fn apply_vertex_layout<V: Vertex>() {
+    apply_vertex_attributes(&V::attributes());
+}
+
+pub fn apply_vertex_attributes(attributes: &[VertexAttribute]) {
+    for a in attributes {
+        let (size, stride, offset) = (a.components as i32, a.stride as i32, a.offset as *const std::ffi::c_void);
         unsafe {
-            let name = CString::new(name.to_string())?;
-            Ok(gl::GetUniformLocation(
-                program,
-                name.as_ptr() as *const _,
-            ))
+            gl::EnableVertexAttribArray(a.location);
+            if a.gl_type == gl::DOUBLE {
+                gl::VertexAttribLPointer(a.location, size, a.gl_type, stride, offset);
+            } else if a.is_integer() && !a.normalized {
+                gl::VertexAttribIPointer(a.location, size, a.gl_type, stride, offset);
+            } else {
+                let normalized = if a.normalized { gl::TRUE } else { gl::FALSE };
+                gl::VertexAttribPointer(a.location, size, a.gl_type, normalized, stride, offset);
+            }
         }
     }
-    fn bind(&self, name: &dyn ToString, program: u32) -> Result<(), std::ffi::NulError>;
-}
\ No newline at end of file
+}
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::ffi::NulError;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+
+use crate::gfx::backend::{GlBackend, UniformBackend};
+use crate::gfx::GfxBind;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GfxError {
+    Nul(NulError),
+    ///The program has no active uniform with this name (`glGetUniformLocation` returned `-1`).
+    MissingUniform { program: u32, name: String },
+}
+
+impl Display for GfxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GfxError::Nul(e) => write!(f, "invalid uniform name: {}", e),
+            GfxError::MissingUniform { program, name } => write!(f, "program {} has no active uniform `{}`", program, name),
+        }
+    }
+}
+
+impl Error for GfxError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GfxError::Nul(e) => Some(e),
+            GfxError::MissingUniform { .. } => None,
+        }
+    }
+}
+
+impl From<NulError> for GfxError {
+    fn from(value: NulError) -> Self {
+        Self::Nul(value)
+    }
+}
+
+fn resolve(backend: &mut dyn UniformBackend, program: u32, name: &str) -> Result<i32, GfxError> {
+    match backend.uniform_location(program, name)? {
+        -1 => Err(GfxError::MissingUniform { program, name: name.to_string() }),
+        loc => Ok(loc),
+    }
+}
+
+///Caches uniform locations of one program so each name is only looked up once.
+///Call [`UniformCache::clear`] after relinking the program.
+#[derive(Debug, Clone)]
+pub struct UniformCache {
+    program: u32,
+    locations: HashMap<String, i32>,
+}
+
+impl UniformCache {
+    pub fn new(program: u32) -> Self {
+        Self {
+            program,
+            locations: HashMap::new(),
+        }
+    }
+
+    pub fn program(&self) -> u32 {
+        self.program
+    }
+
+    pub fn clear(&mut self) {
+        self.locations.clear();
+    }
+
+    pub fn location(&mut self, name: &str) -> Result<i32, GfxError> {
+        self.location_with(&mut GlBackend, name)
+    }
+
+    ///Missing uniforms are cached as well, so repeated lookups of a misspelt name stay cheap but keep failing.
+    pub fn location_with(&mut self, backend: &mut dyn UniformBackend, name: &str) -> Result<i32, GfxError> {
+        let loc = match self.locations.get(name) {
+            Some(loc) => *loc,
+            None => {
+                let loc = backend.uniform_location(self.program, name)?;
+                self.locations.insert(name.to_string(), loc);
+                loc
+            }
+        };
+        match loc {
+            -1 => Err(GfxError::MissingUniform { program: self.program, name: name.to_string() }),
+            loc => Ok(loc),
+        }
+    }
+
+    pub fn bind<T: GfxBind + ?Sized>(&mut self, name: &str, value: &T) -> Result<(), GfxError> {
+        self.bind_with(&mut GlBackend, name, value)
+    }
+
+    pub fn bind_with<T: GfxBind + ?Sized>(&mut self, backend: &mut dyn UniformBackend, name: &str, value: &T) -> Result<(), GfxError> {
+        let loc = self.location_with(backend, name)?;
+        value.upload(backend, loc);
+        Ok(())
+    }
+
+    pub fn uniform<T: GfxBind + ?Sized>(&mut self, name: &str) -> Result<Uniform<T>, GfxError> {
+        self.uniform_with(&mut GlBackend, name)
+    }
+
+    pub fn uniform_with<T: GfxBind + ?Sized>(&mut self, backend: &mut dyn UniformBackend, name: &str) -> Result<Uniform<T>, GfxError> {
+        Ok(Uniform::from_location(self.location_with(backend, name)?))
+    }
+}
+
+///A uniform location resolved once, typed by the value it accepts.
+#[derive(Debug)]
+pub struct Uniform<T: GfxBind + ?Sized> {
+    location: i32,
+    _value: PhantomData<fn(&T)>,
+}
+
+impl<T: GfxBind + ?Sized> Clone for Uniform<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GfxBind + ?Sized> Copy for Uniform<T> {}
+
+impl<T: GfxBind + ?Sized> Uniform<T> {
+    pub fn new(program: u32, name: &str) -> Result<Self, GfxError> {
+        Self::new_with(&mut GlBackend, program, name)
+    }
+
+    pub fn new_with(backend: &mut dyn UniformBackend, program: u32, name: &str) -> Result<Self, GfxError> {
+        Ok(Self::from_location(resolve(backend, program, name)?))
+    }
+
+    fn from_location(location: i32) -> Self {
+        Self {
+            location,
+            _value: PhantomData,
+        }
+    }
+
+    pub fn location(&self) -> i32 {
+        self.location
+    }
+
+    pub fn set(&self, value: &T) {
+        self.set_with(&mut GlBackend, value)
+    }
+
+    pub fn set_with(&self, backend: &mut dyn UniformBackend, value: &T) {
+        value.upload(backend, self.location);
+    }
+}
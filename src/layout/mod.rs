@@ -0,0 +1,205 @@
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use crate::vector::Vector;
+
+///Memory layout rules for GLSL interface blocks.
+///`Std140` is used for uniform blocks, `Std430` for shader storage blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    Std140,
+    Std430,
+}
+
+impl Layout {
+    ///std140 rounds the alignment of arrays and structs up to that of a `vec4`, std430 does not.
+    fn aggregate_alignment(self, alignment: usize) -> usize {
+        match self {
+            Layout::Std140 => round_up(alignment, 16),
+            Layout::Std430 => alignment,
+        }
+    }
+}
+
+fn round_up(value: usize, alignment: usize) -> usize {
+    value.div_ceil(alignment) * alignment
+}
+
+///Scalars that can be stored in an interface block, written in native byte order.
+pub trait BlockScalar: Copy {
+    const SIZE: usize;
+
+    fn write_bytes(self, out: &mut Vec<u8>);
+}
+
+macro_rules! block_scalar {
+    ($($s:ty),*) => {
+        $(
+            impl BlockScalar for $s {
+                const SIZE: usize = std::mem::size_of::<$s>();
+
+                fn write_bytes(self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_ne_bytes());
+                }
+            }
+
+            impl BlockLayout for $s {
+                fn encode(&self, encoder: &mut BlockEncoder) -> usize {
+                    let mut bytes = Vec::with_capacity(<$s>::SIZE);
+                    self.write_bytes(&mut bytes);
+                    encoder.push_raw(<$s>::SIZE, &bytes)
+                }
+            }
+        )*
+    };
+}
+
+block_scalar!(f32, f64, i32, u32);
+
+///A value that can be written into an interface block.
+pub trait BlockLayout {
+    ///Aligns the encoder as the layout requires, writes the value and returns the offset it was written at.
+    fn encode(&self, encoder: &mut BlockEncoder) -> usize;
+}
+
+///`vecN` is aligned to N scalars, except that a `vec3` is aligned like a `vec4`.
+impl<const L: usize, S: BlockScalar> BlockLayout for Vector<L, S> {
+    fn encode(&self, encoder: &mut BlockEncoder) -> usize {
+        let components = if L == 3 { 4 } else { L };
+        let mut bytes = Vec::with_capacity(L * S::SIZE);
+        for s in self.0 {
+            s.write_bytes(&mut bytes);
+        }
+        encoder.push_raw(components * S::SIZE, &bytes)
+    }
+}
+
+///Matrices are stored as an array of column vectors. As for uniform uploads, the rows of `self.0` are handed to GL as
+///its columns, so a `Matrix<M, N, S>` becomes a GLSL `matMxN`.
+impl<const M: usize, const N: usize, S: Scalar + BlockScalar> BlockLayout for Matrix<M, N, S> {
+    fn encode(&self, encoder: &mut BlockEncoder) -> usize {
+        encoder.push_array(&self.0.map(Vector))
+    }
+}
+
+impl<T: BlockLayout> BlockLayout for [T] {
+    fn encode(&self, encoder: &mut BlockEncoder) -> usize {
+        encoder.push_array(self)
+    }
+}
+
+impl<const K: usize, T: BlockLayout> BlockLayout for [T; K] {
+    fn encode(&self, encoder: &mut BlockEncoder) -> usize {
+        encoder.push_array(self)
+    }
+}
+
+///Packs values into a byte buffer following std140 or std430 rules.
+///
+///Structs are written with [`BlockEncoder::push_struct`], which is also how [`BlockLayout`] is implemented for user
+///structs.
+#[derive(Debug, Clone)]
+pub struct BlockEncoder {
+    layout: Layout,
+    bytes: Vec<u8>,
+    alignment: usize,
+}
+
+impl BlockEncoder {
+    pub fn new(layout: Layout) -> Self {
+        Self {
+            layout,
+            bytes: Vec::new(),
+            alignment: 1,
+        }
+    }
+
+    pub fn std140() -> Self {
+        Self::new(Layout::Std140)
+    }
+
+    pub fn std430() -> Self {
+        Self::new(Layout::Std430)
+    }
+
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    ///The largest alignment of anything written so far.
+    pub fn alignment(&self) -> usize {
+        self.alignment
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    pub fn push<T: BlockLayout + ?Sized>(&mut self, value: &T) -> usize {
+        value.encode(self)
+    }
+
+    ///Pads to `alignment` and appends `bytes`, returning their offset.
+    pub fn push_raw(&mut self, alignment: usize, bytes: &[u8]) -> usize {
+        let offset = round_up(self.bytes.len(), alignment);
+        self.bytes.resize(offset, 0);
+        self.bytes.extend_from_slice(bytes);
+        self.alignment = self.alignment.max(alignment);
+        offset
+    }
+
+    ///Writes each element at the array stride of the layout and returns the offset of the first one.
+    pub fn push_array<T: BlockLayout>(&mut self, values: &[T]) -> usize {
+        let elements = values.iter().map(|v| {
+            let mut element = BlockEncoder::new(self.layout);
+            v.encode(&mut element);
+            element
+        }).collect::<Vec<BlockEncoder>>();
+
+        let element_alignment = elements.iter().map(|e| e.alignment).max().unwrap_or(1);
+        let alignment = self.layout.aggregate_alignment(element_alignment);
+
+        let mut bytes = Vec::new();
+        for element in elements {
+            let stride = round_up(element.len(), alignment);
+            bytes.extend_from_slice(&element.bytes);
+            bytes.resize(bytes.len() + stride - element.len(), 0);
+        }
+        self.push_raw(alignment, &bytes)
+    }
+
+    ///Writes the members added by `members` as one struct: aligned to its largest member (rounded up to a `vec4` for
+    ///std140) and padded to a multiple of that alignment. Returns the offset of the struct.
+    pub fn push_struct<F: FnOnce(&mut BlockEncoder)>(&mut self, members: F) -> usize {
+        let mut inner = BlockEncoder::new(self.layout);
+        members(&mut inner);
+
+        let alignment = self.layout.aggregate_alignment(inner.alignment);
+        let size = round_up(inner.len(), alignment);
+        inner.bytes.resize(size, 0);
+        self.push_raw(alignment, &inner.bytes)
+    }
+}
+
+pub fn std140<T: BlockLayout + ?Sized>(value: &T) -> Vec<u8> {
+    let mut encoder = BlockEncoder::std140();
+    encoder.push(value);
+    encoder.finish()
+}
+
+pub fn std430<T: BlockLayout + ?Sized>(value: &T) -> Vec<u8> {
+    let mut encoder = BlockEncoder::std430();
+    encoder.push(value);
+    encoder.finish()
+}
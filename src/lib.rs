@@ -6,10 +6,18 @@ pub mod axis_angle;
 pub mod euler_angles;
 pub mod quaternion;
 pub mod functions;
+pub mod geometry;
 pub mod pointer;
 pub mod types;
+pub mod layout;
+pub mod vertex;
+pub mod parse;
+pub mod prelude;
 
-//#[cfg(feature = "gfx")]
+#[cfg(feature = "serde")]
+mod serialization;
+
+#[cfg(feature = "gfx")]
 pub mod gfx;
 
 #[cfg(test)]
@@ -18,13 +26,18 @@ mod tests {
 
     use crate::angle::Angle;
     use crate::euler_angles::EulerAngles;
-    use crate::functions::vec3;
+    use crate::functions::{mat_identity_fill, mat_mul, mat_transpose, vec2, vec3};
+    use crate::layout::{BlockEncoder, BlockLayout};
+    use crate::matrix::mat2::Mat2;
     use crate::matrix::mat3::Mat3;
     use crate::matrix::mat4::Mat4;
+    use crate::matrix::eigen::SymmetricEigen;
+    use crate::matrix::Matrix;
     use crate::matrix::square_matrix::SquareMatrix;
+    use crate::pointer::Pointer;
     use crate::quaternion::Quaternion;
     use crate::scalar::Scalar;
-    use crate::vector::f32::vec3::Vec3F32;
+    use crate::vector::{Vector, Vector2, Vector3};
 
     #[test]
     fn it_works() {
@@ -60,11 +73,17 @@ mod tests {
 
     #[test]
     fn test_look_at() {
-        let eye = vec3(0.0_f32, 0.0, -3.0);
-        let center = vec3(0.0, 0.0, 0.0);
-        let up = Vec3F32::UP;
+        let eye = vec3(2.0_f64, 3.0, -4.0);
+        let center = vec3(-1.0, 0.5, 1.0);
+        let up = vec3(0.0, 1.0, 0.0);
 
-        let look_at = crate::functions::look_at(eye, center, up);
+        //look_at is laid out for upload, transposed from the column vector convention of transform_point
+        let view = mat_transpose(crate::functions::look_at(eye, center, up));
+        assert_vec_close(view.transform_point(eye), vec3(0.0, 0.0, 0.0), 1e-12);
+        assert_vec_close(view.transform_point(center), vec3(0.0, 0.0, -eye.distance(center)), 1e-12);
+        let view_up = view.transform_vector(up);
+        assert!(view_up.x().abs() < 1e-12 && view_up.y() > 0.0);
+        assert!((view.transform_vector(vec3(3.0, -1.0, 2.0)).magnitude() - 14.0_f64.sqrt()).abs() < 1e-12);
     }
 
     #[test]
@@ -133,4 +152,1532 @@ mod tests {
 
         println!("{}", m);
     }
-}
\ No newline at end of file
+
+    fn assert_mat_eq<const M: usize, const N: usize>(a: Matrix<M, N, f64>, b: Matrix<M, N, f64>, eps: f64) {
+        for i in 0..M {
+            for j in 0..N {
+                assert!((a.0[i][j] - b.0[i][j]).abs() <= eps, "{:?} != {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn qr_reconstructs() {
+        let a = Matrix([
+                           [12.0, -51.0, 4.0],
+                           [6.0, 167.0, -68.0],
+                           [-4.0, 24.0, -41.0],
+                           [1.0, 2.0, 3.0],
+                       ]);
+        let qr = a.qr();
+        assert_mat_eq(mat_mul(qr.q, qr.r), a, 1e-9);
+        assert_mat_eq(mat_mul(mat_transpose(qr.q), qr.q), mat_identity_fill(1.0), 1e-9);
+        for i in 1..4 {
+            for j in 0..i.min(3) {
+                assert_eq!(qr.r.0[i][j], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn qr_small_scale() {
+        //Entries far below f32 epsilon still get reduced
+        let a: Matrix<3, 2, f32> = Matrix([[1.0, 2.0], [3.0, 1.0], [2.0, 5.0]]);
+        let b = Vector([1.0, 2.0, 3.0]);
+        let expected = a.solve(b).unwrap();
+        let small = Matrix(a.0.map(|row| row.map(|x| x * 1e-5)));
+        let qr = small.qr();
+        for i in 1..3 {
+            for j in 0..i.min(2) {
+                assert!(qr.r.0[i][j].abs() <= 1e-12, "{:?}", qr.r);
+            }
+        }
+        let x = small.solve(b * 1e-5).unwrap();
+        for i in 0..2 {
+            assert!((x.0[i] - expected.0[i]).abs() < 1e-4, "{:?} != {:?}", x, expected);
+        }
+    }
+
+    #[test]
+    fn least_squares_line_fit() {
+        //y = 2x + 1 sampled with symmetric noise
+        let a: Matrix<4, 2, f64> = Matrix([[0.0, 1.0], [1.0, 1.0], [2.0, 1.0], [3.0, 1.0]]);
+        let b = Vector([1.1, 2.9, 5.1, 6.9]);
+        let x = a.solve(b).unwrap();
+        assert!((x.0[0] - 1.96).abs() < 1e-9);
+        assert!((x.0[1] - 1.06).abs() < 1e-9);
+
+        let singular = Matrix([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+        assert!(singular.solve(Vector([1.0, 2.0, 3.0])).is_none());
+    }
+
+    #[test]
+    fn cholesky_solves_spd() {
+        let a = Matrix([[4.0, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]]);
+        let c = a.cholesky().unwrap();
+        assert_mat_eq(c.l, Matrix([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]), 1e-12);
+        let x = c.solve(Vector([1.0, 2.0, 3.0]));
+        let b = a * x;
+        for i in 0..3 {
+            assert!((b.0[i] - (i + 1) as f64).abs() < 1e-9);
+        }
+        assert!((c.determinant() - 36.0).abs() < 1e-9);
+        assert!(Matrix([[1.0, 2.0], [2.0, 1.0]]).cholesky().is_none());
+    }
+
+    #[test]
+    fn svd_pseudo_inverse_rank_polar() {
+        let a: Matrix<2, 3, f64> = Matrix([[3.0, 2.0, 2.0], [2.0, 3.0, -2.0]]);
+        let svd = a.svd();
+        assert!((svd.sigma.0[0] - 5.0).abs() < 1e-9);
+        assert!((svd.sigma.0[1] - 3.0).abs() < 1e-9);
+        assert!(svd.sigma.0[2].abs() < 1e-9);
+        assert_eq!(svd.rank(), 2);
+
+        let pinv = a.pseudo_inverse();
+        assert_mat_eq(mat_mul(mat_mul(a, pinv), a), a, 1e-9);
+
+        let singular = Matrix([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+        assert_eq!(singular.rank(), 1);
+        assert_mat_eq(mat_mul(mat_mul(singular, singular.pseudo_inverse()), singular), singular, 1e-9);
+
+        let m = Matrix([[2.0, -1.0, 0.0], [1.0, 3.0, 1.0], [0.0, 1.0, 4.0]]);
+        let (r, p) = m.polar();
+        assert_mat_eq(mat_mul(r, p), m, 1e-9);
+        assert_mat_eq(mat_mul(mat_transpose(r), r), mat_identity_fill(1.0), 1e-9);
+        assert_mat_eq(p, mat_transpose(p), 1e-9);
+    }
+
+    fn assert_eigen<const L: usize>(a: Matrix<L, L, f64>, e: SymmetricEigen<L, f64>, eps: f64) {
+        let mut lambda: Matrix<L, L, f64> = Matrix::ZERO;
+        for i in 0..L {
+            lambda.0[i][i] = e.values.0[i];
+            if i > 0 {
+                assert!(e.values.0[i - 1] >= e.values.0[i]);
+            }
+        }
+        assert_mat_eq(mat_mul(mat_transpose(e.vectors), e.vectors), mat_identity_fill(1.0), eps);
+        assert_mat_eq(mat_mul(mat_mul(e.vectors, lambda), mat_transpose(e.vectors)), a, eps);
+    }
+
+    #[test]
+    fn symmetric_eigen_closed_form() {
+        let a = crate::functions::mat2(2.0_f64, 1.0, 1.0, 2.0);
+        let e = a.symmetric_eigen();
+        assert!((e.values.0[0] - 3.0).abs() < 1e-12 && (e.values.0[1] - 1.0).abs() < 1e-12);
+        assert_eigen(a, e, 1e-12);
+
+        let a = crate::functions::mat3(
+            2.0, -1.0, 0.0,
+            -1.0, 2.0, -1.0,
+            0.0, -1.0, 2.0,
+        );
+        let e = a.symmetric_eigen();
+        let r = 2.0_f64.sqrt();
+        assert!((e.values.0[0] - (2.0 + r)).abs() < 1e-12);
+        assert!((e.values.0[1] - 2.0).abs() < 1e-12);
+        assert!((e.values.0[2] - (2.0 - r)).abs() < 1e-12);
+        assert_eigen(a, e, 1e-12);
+
+        //Repeated eigenvalue
+        let a = crate::functions::mat3(
+            3.0_f64, 1.0, 1.0,
+            1.0, 3.0, 1.0,
+            1.0, 1.0, 3.0,
+        );
+        let e = a.symmetric_eigen();
+        assert!((e.values.0[0] - 5.0).abs() < 1e-12);
+        assert!((e.values.0[1] - 2.0).abs() < 1e-12);
+        assert!((e.values.0[2] - 2.0).abs() < 1e-12);
+        assert_eigen(a, e, 1e-12);
+
+        assert_eigen(Mat3::identity_fill(4.0), Mat3::identity_fill(4.0).symmetric_eigen(), 1e-12);
+
+        //Tiny matrices are not mistaken for multiples of the identity
+        let scale = 1e-20;
+        let tiny = crate::functions::mat3(
+            2.0 * scale, -scale, 0.0,
+            -scale, 2.0 * scale, -scale,
+            0.0, -scale, 2.0 * scale,
+        );
+        let e = tiny.symmetric_eigen();
+        assert!((e.values.0[0] - (2.0 + r) * scale).abs() < 1e-12 * scale);
+        assert!((e.values.0[2] - (2.0 - r) * scale).abs() < 1e-12 * scale);
+        assert_eigen(tiny * (1.0 / scale), SymmetricEigen { values: e.values * (1.0 / scale), vectors: e.vectors }, 1e-12);
+    }
+
+    #[test]
+    fn symmetric_eigen_jacobi_mat4() {
+        let a = crate::functions::mat4(
+            4.0, 1.0, -2.0, 2.0,
+            1.0, 2.0, 0.0, 1.0,
+            -2.0, 0.0, 3.0, -2.0,
+            2.0, 1.0, -2.0, -1.0,
+        );
+        let e = a.symmetric_eigen();
+        assert_eigen(a, e, 1e-10);
+        let trace: f64 = e.values.0.iter().sum();
+        assert!((trace - 8.0).abs() < 1e-10);
+
+        let b = crate::functions::mat3(
+            1.0_f64, 0.5, 0.25,
+            0.5, 2.0, 0.75,
+            0.25, 0.75, 3.0,
+        );
+        let jacobi = crate::matrix::eigen::symmetric_eigen_jacobi(b);
+        let closed = b.symmetric_eigen();
+        for i in 0..3 {
+            assert!((jacobi.values.0[i] - closed.values.0[i]).abs() < 1e-10);
+        }
+
+        let c: Mat3<f32> = crate::functions::mat3(5.0, 2.0, 0.0, 2.0, 5.0, 0.0, 0.0, 0.0, 1.0);
+        let e = c.symmetric_eigen();
+        assert!((e.values.0[0] - 7.0).abs() < 1e-5);
+        assert!((e.values.0[2] - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn matrix_arithmetic() {
+        let a = crate::functions::mat2(1.0_f64, 2.0, 3.0, 4.0);
+        let b = crate::functions::mat2(5.0, 6.0, 7.0, 8.0);
+
+        assert_mat_eq(a + b, crate::functions::mat2(6.0, 8.0, 10.0, 12.0), 0.0);
+        assert_mat_eq(b - a, crate::functions::mat2(4.0, 4.0, 4.0, 4.0), 0.0);
+        assert_mat_eq(-a, crate::functions::mat2(-1.0, -2.0, -3.0, -4.0), 0.0);
+        assert_mat_eq(a * 2.0, crate::functions::mat2(2.0, 4.0, 6.0, 8.0), 0.0);
+        assert_mat_eq(a / 2.0, crate::functions::mat2(0.5, 1.0, 1.5, 2.0), 0.0);
+        assert_mat_eq(a.hadamard(b), crate::functions::mat2(5.0, 12.0, 21.0, 32.0), 0.0);
+        //Matrix product is still available for square aliases
+        assert_mat_eq(a * b, crate::functions::mat2(19.0, 22.0, 43.0, 50.0), 0.0);
+
+        let mut c = a;
+        c += b;
+        c -= a;
+        c *= 3.0;
+        c /= 1.5;
+        assert_mat_eq(c, b * 2.0, 1e-12);
+
+        let blend = a * 0.25 + b * 0.75;
+        assert_mat_eq(blend, crate::functions::mat2(4.0, 5.0, 6.0, 7.0), 1e-12);
+
+        let r: Matrix<2, 3, f64> = Matrix([[1.0, -7.0, 2.0], [0.0, 4.0, -4.0]]);
+        assert_eq!(r.max_abs(), 7.0);
+        assert!((r.frobenius_norm() - 86.0_f64.sqrt()).abs() < 1e-12);
+        let f: Matrix<2, 3, f32> = r.map(|s| s as f32);
+        assert_eq!(f.0[0][1], -7.0_f32);
+        assert_mat_eq(r.zip_map(r, f64::max), r, 0.0);
+    }
+
+    #[test]
+    fn matrix_display() {
+        let m = crate::functions::mat2(1.0_f64, -22.5, 3.25, 4.0);
+        assert_eq!(m.to_string(), "⎡ 1.0000  -22.5000 ⎤\n⎣ 3.2500    4.0000 ⎦");
+
+        assert_eq!(m.display(1).to_string(), "⎡ 1.0  -22.5 ⎤\n⎣ 3.2    4.0 ⎦");
+        assert_eq!(format!("{:.2}", m), "⎡ 1.00  -22.50 ⎤\n⎣ 3.25    4.00 ⎦");
+        assert_eq!(format!("{:.2}", m.display(1)), "⎡ 1.00  -22.50 ⎤\n⎣ 3.25    4.00 ⎦");
+        assert_eq!(format!("{:#}", m.display(1)), "[[1.0, -22.5], [3.2, 4.0]]");
+        assert_eq!(format!("{:#.0}", m), "[[1, -22], [3, 4]]");
+
+        let row: Matrix<1, 3, f32> = Matrix([[1.0, 2.0, 3.0]]);
+        assert_eq!(format!("{:.1}", row), "[ 1.0  2.0  3.0 ]");
+
+        let m3 = Mat3::<f32>::IDENTITY;
+        assert_eq!(format!("{:.0}", m3), "⎡ 1  0  0 ⎤\n⎢ 0  1  0 ⎥\n⎣ 0  0  1 ⎦");
+    }
+
+    #[test]
+    fn quaternion_display() {
+        let q = Quaternion::new(vec3(0.5_f64, -0.25, 0.0), 1.0);
+        assert_eq!(q.to_string(), "1.0000 + 0.5000i - 0.2500j + 0.0000k");
+        assert_eq!(format!("{:.1}", q), "1.0 + 0.5i - 0.2j + 0.0k");
+        assert_eq!(format!("{:#.2}", q), "(1.00, [0.50, -0.25, 0.00])");
+    }
+
+    #[test]
+    fn matrix_layout() {
+        use std::mem::{align_of, size_of};
+
+        assert_eq!(size_of::<Matrix<3, 4, f32>>(), size_of::<[[f32; 4]; 3]>());
+        assert_eq!(align_of::<Matrix<3, 4, f32>>(), align_of::<[[f32; 4]; 3]>());
+        assert_eq!(size_of::<Mat4<f64>>(), 16 * size_of::<f64>());
+
+        let m = crate::functions::mat2(1.0_f32, 2.0, 3.0, 4.0);
+        let flat = unsafe { std::slice::from_raw_parts(m.as_ptr(), 4) };
+        assert_eq!(flat, &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(&m as *const _ as *const f32, m.as_ptr());
+    }
+
+    #[cfg(feature = "gfx")]
+    #[test]
+    fn gfx_bind_records_uniforms() {
+        use crate::gfx::backend::{RecordingBackend, UniformData};
+        use crate::gfx::GfxBind;
+
+        let mut backend = RecordingBackend::new();
+        let model = backend.declare(1, "model");
+        backend.declare(1, "lights");
+        backend.declare(1, "angle");
+        backend.declare(1, "cell");
+
+        let m = crate::functions::mat2(1.0_f32, 2.0, 3.0, 4.0);
+        m.bind_with(&mut backend, &"model", 1).unwrap();
+        let call = backend.last(1, "model").unwrap();
+        assert_eq!(call.location, model);
+        assert!(call.matrix && !call.transpose);
+        assert_eq!(call.components, 2);
+        assert_eq!(call.data, UniformData::Float(vec![1.0, 2.0, 3.0, 4.0]));
+
+        let lights = [vec3(1.0_f64, 2.0, 3.0), vec3(4.0, 5.0, 6.0)];
+        lights[..].bind_with(&mut backend, &"lights", 1).unwrap();
+        let call = backend.last(1, "lights").unwrap();
+        assert_eq!(call.components, 3);
+        assert_eq!(call.data, UniformData::Double(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+
+        Angle::Degrees(180.0_f32).bind_with(&mut backend, &"angle", 1).unwrap();
+        assert_eq!(backend.last(1, "angle").unwrap().data, UniformData::Float(vec![std::f32::consts::PI]));
+
+        Vector([3_i32, -4]).bind_with(&mut backend, &"cell", 1).unwrap();
+        assert_eq!(backend.last(1, "cell").unwrap().data, UniformData::Int(vec![3, -4]));
+
+        //Unknown uniforms resolve to -1 and are ignored, as in GL
+        let calls = backend.calls.len();
+        m.bind_with(&mut backend, &"missing", 1).unwrap();
+        m.bind_with(&mut backend, &"model", 2).unwrap();
+        assert_eq!(backend.calls.len(), calls);
+        assert!(m.bind_with(&mut backend, &"bad\0name", 1).is_err());
+    }
+
+    #[cfg(feature = "gfx")]
+    #[test]
+    fn gfx_uniform_cache_and_handles() {
+        use crate::gfx::backend::{RecordingBackend, UniformData};
+        use crate::gfx::uniform::{GfxError, Uniform, UniformCache};
+        use crate::vector::Vector3;
+
+        let mut backend = RecordingBackend::new();
+        backend.declare(7, "model");
+        backend.declare(7, "tint");
+
+        let mut cache = UniformCache::new(7);
+        for i in 0..10 {
+            cache.bind_with(&mut backend, "model", &Mat4::identity_fill(i as f32)).unwrap();
+        }
+        assert_eq!(backend.lookups(), 1);
+        assert_eq!(backend.calls.len(), 10);
+
+        let missing = cache.bind_with(&mut backend, "modle", &Mat4::<f32>::IDENTITY);
+        assert_eq!(missing, Err(GfxError::MissingUniform { program: 7, name: "modle".to_string() }));
+        assert!(cache.location_with(&mut backend, "modle").is_err());
+        assert_eq!(backend.lookups(), 2);
+
+        let tint: Uniform<Vector3<f32>> = cache.uniform_with(&mut backend, "tint").unwrap();
+        tint.set_with(&mut backend, &vec3(1.0, 0.5, 0.25));
+        assert_eq!(backend.last(7, "tint").unwrap().data, UniformData::Float(vec![1.0, 0.5, 0.25]));
+        assert_eq!(backend.lookups(), 3);
+
+        let err = Uniform::<Mat4<f32>>::new_with(&mut backend, 8, "model").unwrap_err();
+        assert_eq!(err.to_string(), "program 8 has no active uniform `model`");
+    }
+
+    struct BlockF {
+        d: i32,
+        e: Vector<2, i32>,
+    }
+
+    impl BlockLayout for BlockF {
+        fn encode(&self, encoder: &mut BlockEncoder) -> usize {
+            encoder.push_struct(|e| {
+                e.push(&self.d);
+                e.push(&self.e);
+            })
+        }
+    }
+
+    struct BlockO {
+        j: Vector<3, u32>,
+        k: Vector2<f32>,
+        l: [f32; 2],
+        m: Vector2<f32>,
+        n: [Mat3<f32>; 2],
+    }
+
+    impl BlockLayout for BlockO {
+        fn encode(&self, encoder: &mut BlockEncoder) -> usize {
+            encoder.push_struct(|e| {
+                e.push(&self.j);
+                e.push(&self.k);
+                e.push(&self.l);
+                e.push(&self.m);
+                e.push(&self.n);
+            })
+        }
+    }
+
+    ///The example block from the ARB_uniform_buffer_object specification; returns the offset of every member.
+    fn encode_spec_block(encoder: &mut BlockEncoder) -> Vec<usize> {
+        let o = BlockO {
+            j: Vector([1, 2, 3]),
+            k: Vector([4.0, 5.0]),
+            l: [6.0, 7.0],
+            m: Vector([8.0, 9.0]),
+            n: [Mat3::IDENTITY, Mat3::IDENTITY],
+        };
+        let f = BlockF { d: 1, e: Vector([2, 3]) };
+        let i: Matrix<2, 3, f32> = Matrix([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let mut offsets = vec![
+            encoder.push(&1.0_f32),
+            encoder.push(&vec2(1.0_f32, 2.0)),
+            encoder.push(&vec3(1.0_f32, 2.0, 3.0)),
+            encoder.push(&f),
+            encoder.push(&1.0_f32),
+            encoder.push(&[1.0_f32, 2.0]),
+            encoder.push(&i),
+        ];
+        offsets.push(encoder.push(&[o]));
+        offsets
+    }
+
+    #[test]
+    fn std140_spec_offsets() {
+        let mut encoder = BlockEncoder::std140();
+        assert_eq!(encode_spec_block(&mut encoder), vec![0, 8, 16, 32, 48, 64, 96, 128]);
+        assert_eq!(encoder.len(), 304);
+
+        let bytes = encoder.bytes();
+        let float_at = |offset: usize| f32::from_ne_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        let int_at = |offset: usize| i32::from_ne_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        //f.e, h[1], i column 1, o.k, o.l[1], o.m, o.n[1][2][2]
+        assert_eq!(int_at(40), 2);
+        assert_eq!(float_at(80), 2.0);
+        assert_eq!(float_at(112), 4.0);
+        assert_eq!(float_at(144), 4.0);
+        assert_eq!(float_at(176), 7.0);
+        assert_eq!(float_at(192), 8.0);
+        assert_eq!(float_at(256 + 2 * 16 + 8), 1.0);
+
+        let o = BlockO { j: Vector([0; 3]), k: Vector::ZERO, l: [0.0; 2], m: Vector::ZERO, n: [Mat3::IDENTITY; 2] };
+        let mut array = BlockEncoder::std140();
+        array.push(&[BlockF { d: 0, e: Vector([0, 0]) }]);
+        assert_eq!(array.push(&[o]), 16);
+        assert_eq!(array.len(), 16 + 176);
+    }
+
+    #[test]
+    fn std430_spec_offsets() {
+        let mut encoder = BlockEncoder::std430();
+        assert_eq!(encode_spec_block(&mut encoder), vec![0, 8, 16, 32, 48, 52, 64, 96]);
+        assert_eq!(encoder.len(), 240);
+        let bytes = encoder.bytes();
+        let float_at = |offset: usize| f32::from_ne_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        //h[1], o.l[1], o.m
+        assert_eq!(float_at(56), 2.0);
+        assert_eq!(float_at(96 + 28), 7.0);
+        assert_eq!(float_at(96 + 32), 8.0);
+    }
+
+    #[test]
+    fn block_padding() {
+        //mat3 is three vec4 columns in both layouts, mat2 only differs in std140
+        assert_eq!(crate::layout::std140(&Mat3::<f32>::IDENTITY).len(), 48);
+        assert_eq!(crate::layout::std430(&Mat3::<f32>::IDENTITY).len(), 48);
+        assert_eq!(crate::layout::std140(&Mat4::<f32>::IDENTITY).len(), 64);
+        assert_eq!(crate::layout::std140(&crate::functions::mat2(1.0_f32, 2.0, 3.0, 4.0)).len(), 32);
+        assert_eq!(crate::layout::std430(&crate::functions::mat2(1.0_f32, 2.0, 3.0, 4.0)).len(), 16);
+        assert_eq!(crate::layout::std140(&Mat4::<f64>::IDENTITY).len(), 128);
+
+        let m = crate::layout::std140(&Mat3::<f32>::IDENTITY);
+        assert_eq!(&m[12..16], &[0; 4]);
+        assert_eq!(&m[20..24], &1.0_f32.to_ne_bytes());
+
+        //A float packs into the tail of a vec3, but a vec3 array pads every element
+        let mut e = BlockEncoder::std430();
+        e.push(&vec3(1.0_f32, 2.0, 3.0));
+        assert_eq!(e.push(&4.0_f32), 12);
+        assert_eq!(crate::layout::std430(&[vec3(1.0_f32, 2.0, 3.0); 2]).len(), 32);
+        assert_eq!(crate::layout::std430(&[vec2(1.0_f32, 2.0); 3][..]).len(), 24);
+        assert_eq!(crate::layout::std140(&[vec2(1.0_f32, 2.0); 3][..]).len(), 48);
+    }
+
+    #[repr(C)]
+    struct LitVertex {
+        position: Vector3<f32>,
+        normal: Vector3<f32>,
+        uv: Vector2<f32>,
+        bone_ids: Vector<4, i32>,
+        color: Vector<4, u32>,
+        weight: f64,
+    }
+
+    crate::vertex_layout!(LitVertex {
+        position: 0,
+        normal: 1,
+        uv: 2,
+        bone_ids: 3,
+        color: 4 normalized,
+        weight: 5,
+    });
+
+    #[test]
+    fn vertex_attribute_layout() {
+        use crate::vertex::{Vertex, VertexAttribute, GL_DOUBLE, GL_FLOAT, GL_INT, GL_UNSIGNED_INT};
+
+        let attributes = LitVertex::attributes();
+        assert_eq!(LitVertex::stride(), 72);
+        assert_eq!(attributes.len(), 6);
+        assert_eq!(attributes[0], VertexAttribute { location: 0, components: 3, gl_type: GL_FLOAT, normalized: false, offset: 0, stride: 72 });
+        assert_eq!(attributes[1].offset, Vector3::<f32>::STRIDE);
+        assert_eq!((attributes[2].components, attributes[2].offset), (2, 24));
+        assert_eq!((attributes[3].gl_type, attributes[3].offset), (GL_INT, 32));
+        assert!(attributes[3].is_integer() && !attributes[3].normalized);
+        assert_eq!((attributes[4].gl_type, attributes[4].normalized), (GL_UNSIGNED_INT, true));
+        assert_eq!((attributes[5].gl_type, attributes[5].components, attributes[5].offset), (GL_DOUBLE, 1, 64));
+        assert!(attributes.iter().all(|a| a.stride == 72));
+    }
+
+    #[test]
+    fn byte_views() {
+        use crate::pointer::{flatten, flatten_matrices, flatten_mut, slice_as_bytes};
+
+        let mut v = vec3(1.0_f32, 2.0, 3.0);
+        assert_eq!(v.as_slice(), &[1.0, 2.0, 3.0]);
+        v.as_mut_slice()[1] = 5.0;
+        assert_eq!(v.y(), 5.0);
+        assert_eq!(v.as_bytes().len(), 12);
+        assert_eq!(&v.as_bytes()[4..8], &5.0_f32.to_ne_bytes());
+
+        let mut m = crate::functions::mat2(1.0_f64, 2.0, 3.0, 4.0);
+        assert_eq!(m.as_slice(), &[1.0, 2.0, 3.0, 4.0]);
+        m.as_mut_slice()[2] = 7.0;
+        assert_eq!(m.0[1][0], 7.0);
+        assert_eq!(m.as_bytes().len(), 32);
+
+        let mut q = Quaternion::new(vec3(1.0_f32, 2.0, 3.0), 4.0);
+        assert_eq!(q.as_slice(), &[1.0, 2.0, 3.0, 4.0]);
+        q.as_mut_slice()[3] = 0.5;
+        assert_eq!(*q.w(), 0.5);
+        assert_eq!(q.as_bytes().len(), 16);
+
+        let mut points = [Vector([1_i32, 2]), Vector([3, 4])];
+        assert_eq!(flatten(&points), &[1, 2, 3, 4]);
+        flatten_mut(&mut points)[3] = 9;
+        assert_eq!(points[1].0, [3, 9]);
+        assert_eq!(slice_as_bytes(&points).len(), 16);
+        assert_eq!(flatten_matrices(&[Mat2::<f32>::IDENTITY; 2]), &[1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_pod() {
+        let v = [vec3(1.0_f32, 2.0, 3.0), vec3(4.0, 5.0, 6.0)];
+        let floats: &[f32] = bytemuck::cast_slice(&v);
+        assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        let m: Mat4<f32> = bytemuck::Zeroable::zeroed();
+        assert_eq!(m.as_slice(), &[0.0; 16]);
+
+        let q: Quaternion<f64> = bytemuck::cast([0.0_f64, 0.0, 0.0, 1.0]);
+        assert_eq!(*q.w(), 1.0);
+        assert_eq!(bytemuck::bytes_of(&q), q.as_bytes());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_round_trips() {
+        use crate::axis_angle::AxisAngle;
+
+        let v = vec3(1.0_f32, 2.5, -3.0);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[1.0,2.5,-3.0]");
+        assert_eq!(serde_json::from_str::<Vector3<f32>>(&json).unwrap().0, v.0);
+        assert!(serde_json::from_str::<Vector3<f32>>("[1.0,2.0]").is_err());
+        assert!(serde_json::from_str::<Vector3<f32>>("[1.0,2.0,3.0,4.0]").is_err());
+
+        let m = crate::functions::mat2(1.0_f64, 2.0, 3.0, 4.0);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, "[[1.0,2.0],[3.0,4.0]]");
+        assert_mat_eq(serde_json::from_str(&json).unwrap(), m, 0.0);
+
+        let q = Quaternion::new(vec3(0.0_f64, 0.5, 0.0), 1.0);
+        let json = serde_json::to_string(&q).unwrap();
+        assert_eq!(json, "[0.0,0.5,0.0,1.0]");
+        assert_eq!(serde_json::from_str::<Quaternion<f64>>(&json).unwrap().as_slice(), q.as_slice());
+
+        let a = Angle::Degrees(90.0_f32);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, r#"{"deg":90.0}"#);
+        assert!(serde_json::from_str::<Angle<f32>>(r#"{"rad":1.5}"#).unwrap().is_radians());
+
+        let e = EulerAngles { roll: Angle::Degrees(10.0_f64), pitch: Angle::Radians(0.5), yaw: Angle::Degrees(-45.0) };
+        let json = serde_json::to_string(&e).unwrap();
+        assert_eq!(json, r#"{"roll":{"deg":10.0},"pitch":{"rad":0.5},"yaw":{"deg":-45.0}}"#);
+        let back: EulerAngles<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!((*back.roll.inner(), *back.pitch.inner(), *back.yaw.inner()), (10.0, 0.5, -45.0));
+        assert!(back.pitch.is_radians() && back.yaw.is_degrees());
+
+        let axis = AxisAngle { alpha: Angle::Degrees(1.0_f32), beta: Angle::Degrees(2.0), gamma: Angle::Radians(3.0) };
+        let back: AxisAngle<f32> = serde_json::from_str(&serde_json::to_string(&axis).unwrap()).unwrap();
+        assert_eq!(*back.gamma.inner(), 3.0);
+    }
+
+    #[test]
+    fn parse_round_trips() {
+        let v = vec3(1.5_f32, -2.0, 0.1);
+        assert_eq!(v.to_string().parse::<Vector3<f32>>(), Ok(v));
+        assert_eq!(" [ 1, 2 ] ".parse::<Vector2<f64>>(), Ok(vec2(1.0, 2.0)));
+
+        let m = crate::functions::mat3(1.0_f64, -2.5, 3.25, 0.0, 100.0, -0.125, 7.0, 8.0, 9.0);
+        assert_eq!(m.to_string().parse::<Mat3<f64>>(), Ok(m));
+        assert_eq!(format!("{:#}", m).parse::<Mat3<f64>>(), Ok(m));
+        let row: Matrix<1, 3, f32> = Matrix([[1.0, 2.0, 3.0]]);
+        assert_eq!(row.to_string().parse(), Ok(row));
+        assert_eq!(format!("{:#}", row).parse(), Ok(row));
+
+        let q = Quaternion::new(vec3(0.5_f64, -0.25, 0.0), 1.0);
+        assert_eq!(q.to_string().parse::<Quaternion<f64>>(), Ok(q));
+        assert_eq!(format!("{:#}", q).parse::<Quaternion<f64>>(), Ok(q));
+        assert_eq!("2k - 1 + 1e-1i".parse::<Quaternion<f64>>(), Ok(Quaternion::new(vec3(0.1, 0.0, 2.0), -1.0)));
+
+        for a in [Angle::Degrees(90.0_f32), Angle::Radians(1.25)] {
+            assert_eq!(a.to_string().parse(), Ok(a));
+        }
+        assert_eq!("90deg".parse(), Ok(Angle::Degrees(90.0_f64)));
+        assert_eq!("1.57 rad".parse(), Ok(Angle::Radians(1.57_f64)));
+        assert_eq!("0.25turn".parse(), Ok(Angle::Degrees(90.0_f64)));
+
+        let e = EulerAngles { roll: Angle::Degrees(180.0_f32), pitch: Angle::Radians(0.5), yaw: Angle::Degrees(-45.0) };
+        assert_eq!(e.to_string().parse(), Ok(e));
+
+        //Display rounds matrices and quaternions to the printed places
+        let mut rng = Lcg(39);
+        for _ in 0..20 {
+            let mut m: Mat3<f64> = Matrix::ZERO;
+            m.0.iter_mut().flatten().for_each(|s| *s = rng.range(-1000.0, 1000.0));
+            let rounded = |text: String, places: i32| {
+                let parsed: Mat3<f64> = text.parse().unwrap();
+                let half = 0.5 * 10.0_f64.powi(-places) + 1e-12;
+                assert!(parsed.0.iter().flatten().zip(m.0.iter().flatten()).all(|(p, s)| (p - s).abs() <= half), "{}", text);
+            };
+            rounded(m.to_string(), 4);
+            rounded(format!("{:#}", m), 4);
+            rounded(format!("{:.9}", m), 9);
+            rounded(format!("{:#.9}", m), 9);
+
+            let q = Quaternion::new(rng.vec3(-10.0, 10.0), rng.range(-10.0, 10.0));
+            let parsed: Quaternion<f64> = format!("{:.12}", q).parse().unwrap();
+            let (w, x, y, z) = parsed.w_xyz();
+            let (w0, x0, y0, z0) = q.w_xyz();
+            assert!([w - w0, x - x0, y - y0, z - z0].iter().all(|d| d.abs() <= 5e-13 + 1e-14));
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        use crate::parse::ParseError;
+
+        assert_eq!("".parse::<Vector3<f32>>(), Err(ParseError::Empty));
+        assert_eq!("[1, 2]".parse::<Vector3<f32>>(), Err(ParseError::WrongLength { expected: 3, found: 2 }));
+        assert_eq!("[1, x, 3]".parse::<Vector3<f32>>(), Err(ParseError::InvalidNumber("x".to_string())));
+        assert_eq!("1, 2, 3".parse::<Vector3<f32>>(), Err(ParseError::Malformed("1, 2, 3".to_string())));
+        assert_eq!("[[1, 2], [3, 4]]".parse::<Mat3<f32>>(), Err(ParseError::WrongLength { expected: 3, found: 2 }));
+        assert!("[[1, 2] [3, 4]]".parse::<Mat2<f32>>().is_err());
+        assert_eq!("90grad".parse::<Angle<f32>>(), Err(ParseError::UnknownUnit("grad".to_string())));
+        assert_eq!("deg".parse::<Angle<f32>>(), Err(ParseError::InvalidNumber("deg".to_string())));
+        assert_eq!("inf".parse::<Angle<f64>>(), Ok(Angle::Radians(f64::INFINITY)));
+        assert_eq!("-inf°".parse::<Angle<f64>>(), Ok(Angle::Degrees(f64::NEG_INFINITY)));
+        assert!(matches!("NaN deg".parse::<Angle<f64>>(), Ok(Angle::Degrees(d)) if d.is_nan()));
+        assert!("1 + 2i + 3i".parse::<Quaternion<f32>>().is_err());
+        assert_eq!(
+            "[1, 2]".parse::<Vector3<f32>>().unwrap_err().to_string(),
+            "expected 3 elements, found 2"
+        );
+    }
+
+    #[test]
+    fn glsl_prelude() {
+        use crate::prelude::*;
+
+        let a: vec3 = vec3(0.0, 1.0, -2.0);
+        let b: vec3 = vec3(1.0, 3.0, 2.0);
+        assert_eq!(mix(a, b, 0.25), vec3(0.25, 1.5, -1.0));
+        assert_eq!(mix(a, b, vec3(0.0, 1.0, 0.5)), vec3(0.0, 3.0, 0.0));
+        assert_eq!(mix(2.0_f64, 4.0, 0.5), 3.0);
+
+        assert_eq!(step(1.0, a), vec3(0.0, 1.0, 0.0));
+        assert_eq!(step(vec3(-1.0, 2.0, -3.0), a), vec3(1.0, 0.0, 1.0));
+        assert_eq!(smoothstep(0.0, 1.0, vec3(-1.0, 0.5, 2.0)), vec3(0.0, 0.5, 1.0));
+        assert_eq!(smoothstep(0.0_f32, 2.0, 0.5), 0.15625);
+
+        assert_eq!(fract(vec2(1.25_f32, -1.25)), vec2(0.25, 0.75));
+        assert_eq!(clamp(vec3(-2.0_f32, 0.5, 7.0), 0.0, 1.0), vec3(0.0, 0.5, 1.0));
+        assert_eq!(sign(vec3(-3.0_f32, 0.0, 2.0)), vec3(-1.0, 0.0, 1.0));
+        assert_eq!(r#mod(vec2(5.5_f32, -5.5), 2.0), vec2(1.5, 0.5));
+        assert_eq!(r#mod(5.5_f32, -2.0), -0.5);
+        assert_eq!(inversesqrt(vec2(4.0_f32, 16.0)), vec2(0.5, 0.25));
+        assert_eq!(length(vec3(2.0_f32, 3.0, 6.0)), 7.0);
+        assert_eq!(length(-3.0_f64), 3.0);
+
+        let n = vec3(0.0_f32, 1.0, 0.0);
+        assert_eq!(faceforward(n, vec3(0.0, -1.0, 0.0), n), n);
+        assert_eq!(faceforward(n, vec3(0.0, 1.0, 0.0), n), -n);
+
+        let _: dmat4 = Matrix::ZERO;
+        let _: ivec3 = Vector([1, 2, 3]);
+        let _: float = length(vec2(3.0, 4.0));
+    }
+
+    fn assert_vec_close<const L: usize>(a: Vector<L, f64>, b: Vector<L, f64>, eps: f64) {
+        assert!(a.distance(b) <= eps, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn geometry_primitives() {
+        use crate::geometry::{Aabb, Aabb2, Capsule, Plane, Ray, Segment, Sphere, Transform, Triangle};
+
+        let ray = Ray::new(vec3(0.0, 0.0, 0.0), vec3(0.0, 0.0, 2.0));
+        assert_eq!(ray.direction, vec3(0.0, 0.0, 1.0));
+        assert_eq!(ray.closest_point(vec3(1.0, 0.0, 3.0)), vec3(0.0, 0.0, 3.0));
+        assert_eq!(ray.closest_point(vec3(1.0, 0.0, -3.0)), vec3(0.0, 0.0, 0.0));
+        assert!(ray.contains(vec3(0.0, 0.0, 5.0)) && !ray.contains(vec3(0.0, 0.0, -5.0)));
+
+        let plane = Plane::from_points(vec3(0.0, 1.0, 0.0), vec3(0.0, 1.0, 1.0), vec3(1.0, 1.0, 0.0)).unwrap();
+        assert_vec_close(plane.normal, vec3(0.0, 1.0, 0.0), 1e-12);
+        assert_eq!(plane.signed_distance(vec3(4.0, 3.0, 2.0)), 2.0);
+        assert_eq!(plane.closest_point(vec3(4.0, 3.0, 2.0)), vec3(4.0, 1.0, 2.0));
+        assert!(plane.contains(vec3(-7.0, 1.0, 9.0)));
+        assert!(Plane::from_points(vec3(0.0, 0.0, 0.0), vec3(1.0, 1.0, 1.0), vec3(2.0, 2.0, 2.0)).is_none());
+        let tiny = Plane::from_points(vec3(0.0, 1e-9, 0.0), vec3(0.0, 1e-9, 1e-9), vec3(1e-9, 1e-9, 0.0)).unwrap();
+        assert_vec_close(tiny.normal, vec3(0.0, 1.0, 0.0), 1e-12);
+        assert!(Plane::from_points(vec3(0.0, 0.0, 0.0), vec3(1e6, 0.0, 0.0), vec3(2e6, 1e-6, 0.0)).is_none());
+
+        let sphere = Sphere::new(vec3(1.0, 0.0, 0.0), 2.0);
+        assert!(sphere.contains(vec3(3.0, 0.0, 0.0)) && !sphere.contains(vec3(3.0, 0.1, 0.0)));
+        assert_eq!(sphere.closest_point(vec3(1.0, 5.0, 0.0)), vec3(1.0, 2.0, 0.0));
+        assert_eq!(sphere.closest_point(vec3(1.5, 0.0, 0.0)), vec3(1.5, 0.0, 0.0));
+        assert_eq!(sphere.distance(vec3(1.0, 0.0, -5.0)), 3.0);
+
+        let aabb = Aabb::new(vec3(1.0, 2.0, 3.0), vec3(-1.0, -2.0, -3.0));
+        assert_eq!(aabb.min, vec3(-1.0, -2.0, -3.0));
+        assert!(aabb.contains(vec3(1.0, 0.0, 0.0)) && !aabb.contains(vec3(1.5, 0.0, 0.0)));
+        assert_eq!(aabb.closest_point(vec3(5.0, 0.5, -9.0)), vec3(1.0, 0.5, -3.0));
+        assert_eq!(Aabb::from_points(&[vec3(0.0, 5.0, 1.0), vec3(2.0, -1.0, 1.0)]).unwrap().size(), vec3(2.0, 6.0, 0.0));
+        assert!(Aabb::<3, f64>::from_points(&[]).is_none());
+        assert!(aabb.union(&Aabb::new(vec3(0.0, 0.0, 0.0), vec3(4.0, 4.0, 4.0))).contains_aabb(&aabb));
+
+        let square: Aabb2<f64> = Aabb::new(vec2(0.0, 0.0), vec2(2.0, 2.0));
+        assert_eq!(square.closest_point(vec2(-1.0, 1.0)), vec2(0.0, 1.0));
+        let spin = Mat3::rotation_z(Angle::deg(45.0));
+        let spun = square.transformed(&spin);
+        assert_vec_close(spun.center(), vec2(0.0, 2.0_f64.sqrt()), 1e-12);
+        assert_vec_close(spun.half_extents(), vec2(2.0_f64.sqrt(), 2.0_f64.sqrt()), 1e-12);
+
+        let triangle = Triangle::new(vec3(0.0, 0.0, 0.0), vec3(2.0, 0.0, 0.0), vec3(0.0, 2.0, 0.0));
+        assert_eq!(triangle.normal(), vec3(0.0, 0.0, 1.0));
+        assert_eq!(triangle.area(), 2.0);
+        assert_eq!(triangle.closest_point(vec3(0.5, 0.5, 3.0)), vec3(0.5, 0.5, 0.0));
+        assert_eq!(triangle.closest_point(vec3(-1.0, -1.0, 0.0)), vec3(0.0, 0.0, 0.0));
+        assert_eq!(triangle.closest_point(vec3(1.0, -1.0, 1.0)), vec3(1.0, 0.0, 0.0));
+        assert_vec_close(triangle.closest_point(vec3(2.0, 2.0, 0.0)), vec3(1.0, 1.0, 0.0), 1e-12);
+        assert_vec_close(triangle.barycentric(vec3(0.5, 1.0, 7.0)), vec3(0.25, 0.25, 0.5), 1e-12);
+        assert!(triangle.contains(vec3(1.0, 1.0, 0.0)) && !triangle.contains(vec3(1.0, 1.0, 0.01)));
+
+        let segment = Segment::new(vec3(0.0, 0.0, 0.0), vec3(0.0, 4.0, 0.0));
+        assert_eq!(segment.closest_point(vec3(1.0, 2.0, 0.0)), vec3(0.0, 2.0, 0.0));
+        assert_eq!(segment.closest_point(vec3(1.0, 6.0, 0.0)), vec3(0.0, 4.0, 0.0));
+        let crossing = Segment::new(vec3(-1.0, 1.0, 1.0), vec3(1.0, 1.0, 1.0));
+        assert_eq!(segment.closest_points(&crossing), (vec3(0.0, 1.0, 0.0), vec3(0.0, 1.0, 1.0)));
+        let parallel = Segment::new(vec3(1.0, 5.0, 0.0), vec3(1.0, 9.0, 0.0));
+        assert_eq!(segment.closest_points(&parallel), (vec3(0.0, 4.0, 0.0), vec3(1.0, 5.0, 0.0)));
+        let shrink = |s: &Segment<f64>| Segment::new(s.a * 1e-9, s.b * 1e-9);
+        let (p, q) = shrink(&segment).closest_points(&shrink(&crossing));
+        assert_vec_close(p, vec3(0.0, 1e-9, 0.0), 1e-20);
+        assert_vec_close(q, vec3(0.0, 1e-9, 1e-9), 1e-20);
+
+        let capsule = Capsule::new(vec3(0.0, 0.0, 0.0), vec3(0.0, 4.0, 0.0), 1.0);
+        assert!(capsule.contains(vec3(0.0, 4.9, 0.0)) && !capsule.contains(vec3(1.0, 4.5, 0.0)));
+        assert_eq!(capsule.closest_point(vec3(3.0, 2.0, 0.0)), vec3(1.0, 2.0, 0.0));
+        assert_eq!(capsule.distance(vec3(0.0, -3.0, 0.0)), 2.0);
+
+        //Rotate 90° about z, scale x by 2 and translate
+        let m = Mat4::translation(vec3(1.0, 2.0, 3.0)) * crate::functions::scale(vec3(2.0, 1.0, 1.0)) * Mat4::rotation_z(Angle::deg(90.0));
+        assert_vec_close(m.transform_point(vec3(1.0, 0.0, 0.0)), vec3(1.0, 3.0, 3.0), 1e-12);
+
+        let moved = sphere.transformed(&m);
+        assert_vec_close(moved.center, vec3(1.0, 3.0, 3.0), 1e-12);
+        assert!((moved.radius - 4.0).abs() < 1e-12);
+
+        let moved = aabb.transformed(&m);
+        assert_vec_close(moved.min, vec3(-3.0, 1.0, 0.0), 1e-12);
+        assert_vec_close(moved.max, vec3(5.0, 3.0, 6.0), 1e-12);
+
+        let sheared = Plane::from_point_normal(vec3(0.0, 0.0, 0.0), vec3(1.0, 1.0, 0.0)).transformed(&crate::functions::scale(vec3(2.0, 1.0, 1.0)));
+        assert_vec_close(sheared.normal, vec3(1.0, 2.0, 0.0).normalized(), 1e-12);
+        assert!(sheared.contains(vec3(2.0, -1.0, 0.0)));
+
+        let q = Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Angle::deg(90.0));
+        assert_vec_close(q.rotate(vec3(1.0, 0.0, 0.0)), vec3(0.0, 1.0, 0.0), 1e-12);
+        assert_vec_close(ray.rotated(&Quaternion::from_axis_angle(vec3(1.0, 0.0, 0.0), Angle::deg(90.0))).direction, vec3(0.0, -1.0, 0.0), 1e-12);
+        let turned = triangle.rotated(&q);
+        assert_vec_close(turned.b, vec3(0.0, 2.0, 0.0), 1e-12);
+        assert_vec_close(turned.normal(), vec3(0.0, 0.0, 1.0), 1e-12);
+        assert_vec_close(capsule.rotated(&q).segment.b, vec3(-4.0, 0.0, 0.0), 1e-12);
+    }
+
+    #[test]
+    fn ray_intersections() {
+        use crate::geometry::raycast::{ray_plane, ray_sphere, ray_triangle};
+        use crate::geometry::{Aabb, Capsule, Obb, Plane, Ray, RayCast, Sphere, Triangle};
+
+        let down = Ray::new(vec3(0.0, 5.0, 0.0), vec3(0.0, -1.0, 0.0));
+        let ground = Plane::from_point_normal(vec3(0.0, 1.0, 0.0), vec3(0.0, 1.0, 0.0));
+        let hit = ray_plane(&down, &ground).unwrap();
+        assert_eq!((hit.distance, hit.point, hit.normal), (4.0, vec3(0.0, 1.0, 0.0), vec3(0.0, 1.0, 0.0)));
+        //Two-sided: from below the normal faces the ray
+        let up = Ray::new(vec3(0.0, -1.0, 0.0), vec3(0.0, 1.0, 0.0));
+        assert_eq!(ground.raycast(&up).unwrap().normal, vec3(0.0, -1.0, 0.0));
+        assert!(ray_plane(&Ray::new(vec3(0.0, 5.0, 0.0), vec3(1.0, 0.0, 0.0)), &ground).is_none());
+        assert!(ray_plane(&Ray::new(vec3(0.0, 1.0, 0.0), vec3(1.0, 0.0, 0.0)), &ground).is_none());
+        //A short direction that is not parallel still hits
+        let short = Ray { origin: vec3(0.0, 5.0, 0.0), direction: vec3(1e-10, -1e-10, 0.0) };
+        assert_vec_close(ray_plane(&short, &ground).unwrap().point, vec3(4.0, 1.0, 0.0), 1e-6);
+        assert!(ray_plane(&up, &Plane::from_point_normal(vec3(0.0, -2.0, 0.0), vec3(0.0, 1.0, 0.0))).is_none());
+
+        let sphere = Sphere::new(vec3(0.0, 0.0, 0.0), 1.0);
+        let hit = ray_sphere(&Ray::new(vec3(-5.0, 0.0, 0.0), vec3(1.0, 0.0, 0.0)), &sphere).unwrap();
+        assert_eq!((hit.distance, hit.point, hit.normal), (4.0, vec3(-1.0, 0.0, 0.0), vec3(-1.0, 0.0, 0.0)));
+        let grazing = ray_sphere(&Ray::new(vec3(-5.0, 1.0, 0.0), vec3(1.0, 0.0, 0.0)), &sphere).unwrap();
+        assert_eq!((grazing.distance, grazing.normal), (5.0, vec3(0.0, 1.0, 0.0)));
+        assert!(ray_sphere(&Ray::new(vec3(-5.0, 1.001, 0.0), vec3(1.0, 0.0, 0.0)), &sphere).is_none());
+        assert!(ray_sphere(&Ray::new(vec3(5.0, 0.0, 0.0), vec3(1.0, 0.0, 0.0)), &sphere).is_none());
+        let inside = sphere.raycast(&Ray::new(vec3(0.5, 0.0, 0.0), vec3(1.0, 0.0, 0.0))).unwrap();
+        assert_eq!((inside.distance, inside.normal), (0.5, vec3(1.0, 0.0, 0.0)));
+        let short = Ray { origin: vec3(-5.0, 0.5, 0.0), direction: vec3(1e-9, 0.0, 0.0) };
+        assert_vec_close(ray_sphere(&short, &sphere).unwrap().point, vec3(-0.75_f64.sqrt(), 0.5, 0.0), 1e-9);
+
+        let aabb = Aabb::new(vec3(-1.0, -1.0, -1.0), vec3(1.0, 1.0, 1.0));
+        let hit = aabb.raycast(&Ray::new(vec3(0.5, 5.0, 0.25), vec3(0.0, -1.0, 0.0))).unwrap();
+        assert_eq!((hit.distance, hit.point, hit.normal), (4.0, vec3(0.5, 1.0, 0.25), vec3(0.0, 1.0, 0.0)));
+        let diagonal = aabb.raycast(&Ray::through(vec3(-3.0, -2.0, 0.0), vec3(0.0, 0.0, 0.0))).unwrap();
+        assert_eq!(diagonal.normal, vec3(-1.0, 0.0, 0.0));
+        assert_vec_close(diagonal.point, vec3(-1.0, -2.0 / 3.0, 0.0), 1e-12);
+        //Grazing along a face, then parallel outside of it
+        let along = aabb.raycast(&Ray::new(vec3(-5.0, 1.0, 0.0), vec3(1.0, 0.0, 0.0))).unwrap();
+        assert_eq!((along.distance, along.normal), (4.0, vec3(-1.0, 0.0, 0.0)));
+        assert!(aabb.raycast(&Ray::new(vec3(-5.0, 1.5, 0.0), vec3(1.0, 0.0, 0.0))).is_none());
+        assert!(aabb.raycast(&Ray::new(vec3(-5.0, 0.0, 0.0), vec3(-1.0, 0.0, 0.0))).is_none());
+        let exit = aabb.raycast(&Ray::new(vec3(0.0, 0.0, 0.0), vec3(0.0, 0.0, -1.0))).unwrap();
+        assert_eq!((exit.distance, exit.normal), (1.0, vec3(0.0, 0.0, -1.0)));
+
+        let triangle = Triangle::new(vec3(0.0, 0.0, 0.0), vec3(4.0, 0.0, 0.0), vec3(0.0, 4.0, 0.0));
+        let hit = ray_triangle(&Ray::new(vec3(1.0, 2.0, 3.0), vec3(0.0, 0.0, -1.0)), &triangle).unwrap();
+        assert_eq!((hit.hit.distance, hit.hit.point, hit.hit.normal), (3.0, vec3(1.0, 2.0, 0.0), vec3(0.0, 0.0, 1.0)));
+        assert_eq!(hit.barycentric, vec3(0.25, 0.25, 0.5));
+        let back = ray_triangle(&Ray::new(vec3(1.0, 2.0, -3.0), vec3(0.0, 0.0, 1.0)), &triangle).unwrap();
+        assert_eq!(back.hit.normal, vec3(0.0, 0.0, -1.0));
+        let edge = ray_triangle(&Ray::new(vec3(2.0, 0.0, 1.0), vec3(0.0, 0.0, -1.0)), &triangle).unwrap();
+        assert_eq!(edge.barycentric, vec3(0.5, 0.5, 0.0));
+        assert!(ray_triangle(&Ray::new(vec3(3.0, 3.0, 1.0), vec3(0.0, 0.0, -1.0)), &triangle).is_none());
+        assert!(ray_triangle(&Ray::new(vec3(-1.0, 1.0, 0.0), vec3(1.0, 0.0, 0.0)), &triangle).is_none());
+        assert!(ray_triangle(&Ray::new(vec3(1.0, 1.0, -1.0), vec3(0.0, 0.0, -1.0)), &triangle).is_none());
+
+        let q = Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Angle::deg(45.0));
+        let obb = Obb::from_quaternion(vec3(10.0, 0.0, 0.0), vec3(1.0, 1.0, 1.0), q);
+        let hit = obb.raycast(&Ray::new(vec3(0.0, 0.0, 0.0), vec3(1.0, 0.0, 0.0))).unwrap();
+        assert!((hit.distance - (10.0 - 2.0_f64.sqrt())).abs() < 1e-12);
+        assert_vec_close(hit.point, vec3(10.0 - 2.0_f64.sqrt(), 0.0, 0.0), 1e-12);
+        assert!((hit.normal.x() + hit.normal.y().abs()).abs() < 1e-12 && hit.normal.x() < 0.0);
+        assert!(obb.raycast(&Ray::new(vec3(0.0, 1.5, 0.0), vec3(1.0, 0.0, 0.0))).is_none());
+        let offset = 5.0 / 2.0_f64.sqrt();
+        let face = obb.raycast(&Ray::new(vec3(10.0 + offset, -offset, 0.0), vec3(-1.0, 1.0, 0.0))).unwrap();
+        assert_vec_close(face.normal, vec3(1.0, -1.0, 0.0).normalized(), 1e-12);
+        assert!((face.distance - 4.0).abs() < 1e-12);
+
+        let capsule: Capsule<f64> = Capsule::new(vec3(0.0, 0.0, 0.0), vec3(0.0, 4.0, 0.0), 1.0);
+        let side = capsule.raycast(&Ray::new(vec3(5.0, 2.0, 0.0), vec3(-1.0, 0.0, 0.0))).unwrap();
+        assert_eq!((side.distance, side.point, side.normal), (4.0, vec3(1.0, 2.0, 0.0), vec3(1.0, 0.0, 0.0)));
+        let cap = capsule.raycast(&Ray::new(vec3(0.0, 10.0, 0.0), vec3(0.0, -1.0, 0.0))).unwrap();
+        assert_eq!((cap.distance, cap.normal), (5.0, vec3(0.0, 1.0, 0.0)));
+        let grazing = capsule.raycast(&Ray::new(vec3(1.0, -5.0, 0.0), vec3(0.0, 1.0, 0.0))).unwrap();
+        assert_eq!((grazing.distance, grazing.normal), (5.0, vec3(1.0, 0.0, 0.0)));
+        assert!(capsule.raycast(&Ray::new(vec3(1.5, -5.0, 0.0), vec3(0.0, 1.0, 0.0))).is_none());
+        let exit = capsule.raycast(&Ray::new(vec3(0.0, 2.0, 0.0), vec3(0.0, 0.0, 1.0))).unwrap();
+        assert!((exit.distance - 1.0).abs() < 1e-12);
+        assert_vec_close(exit.normal, vec3(0.0, 0.0, 1.0), 1e-12);
+        let short = Ray { origin: vec3(5.0, 2.0, 0.0), direction: vec3(-1e-9, 0.0, 0.0) };
+        let side = capsule.raycast(&short).unwrap();
+        assert_vec_close(side.point, vec3(1.0, 2.0, 0.0), 1e-9);
+        assert!((side.distance - 4e9).abs() < 1e-3);
+    }
+
+    #[test]
+    fn shape_overlaps() {
+        use crate::geometry::overlap::{aabb_aabb, obb_obb, sphere_aabb, sphere_sphere, sphere_triangle, triangle_aabb};
+        use crate::geometry::{Aabb, Obb, Sphere, Triangle};
+
+        let a = Sphere::new(vec3(0.0, 0.0, 0.0), 1.0);
+        let contact = sphere_sphere(&a, &Sphere::new(vec3(1.5, 0.0, 0.0), 1.0)).unwrap();
+        assert_eq!((contact.normal, contact.depth), (vec3(1.0, 0.0, 0.0), 0.5));
+        assert_eq!(sphere_sphere(&a, &Sphere::new(vec3(0.0, 2.0, 0.0), 1.0)).unwrap().depth, 0.0);
+        assert!(sphere_sphere(&a, &Sphere::new(vec3(0.0, 2.5, 0.0), 1.0)).is_none());
+
+        let unit: Aabb<3, f64> = Aabb::new(vec3(0.0, 0.0, 0.0), vec3(2.0, 2.0, 2.0));
+        let shifted = Aabb::new(vec3(1.5, 0.5, -1.0), vec3(3.5, 2.5, 1.0));
+        let contact = aabb_aabb(&unit, &shifted).unwrap();
+        assert_eq!((contact.normal, contact.depth), (vec3(1.0, 0.0, 0.0), 0.5));
+        assert_eq!(aabb_aabb(&shifted, &unit).unwrap().normal, vec3(-1.0, 0.0, 0.0));
+        assert!(unit.intersects(&shifted) && !unit.intersects(&Aabb::new(vec3(2.1, 0.0, 0.0), vec3(3.0, 1.0, 1.0))));
+        assert!(aabb_aabb(&unit, &Aabb::new(vec3(2.1, 0.0, 0.0), vec3(3.0, 1.0, 1.0))).is_none());
+
+        let contact = sphere_aabb(&Sphere::new(vec3(1.0, 2.5, 1.0), 1.0), &unit).unwrap();
+        assert_eq!((contact.normal, contact.depth), (vec3(0.0, -1.0, 0.0), 0.5));
+        let contact = sphere_aabb(&Sphere::new(vec3(1.8, 1.0, 1.0), 0.5), &unit).unwrap();
+        assert_eq!(contact.normal, vec3(-1.0, 0.0, 0.0));
+        assert!((contact.depth - 0.7).abs() < 1e-12);
+        assert!(sphere_aabb(&Sphere::new(vec3(3.0, 3.0, 1.0), 1.0), &unit).is_none());
+
+        //Two unit cubes, the second turned 45° about z: its edge reaches √2 from its center
+        let straight = Obb::new(vec3(0.0, 0.0, 0.0), vec3(1.0, 1.0, 1.0), Mat3::IDENTITY);
+        let q = Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Angle::deg(45.0));
+        let turned = Obb::from_quaternion(vec3(2.2, 0.0, 0.0), vec3(1.0, 1.0, 1.0), q);
+        let contact = obb_obb(&straight, &turned).unwrap();
+        assert_vec_close(contact.normal, vec3(1.0, 0.0, 0.0), 1e-12);
+        assert!((contact.depth - (2.0_f64.sqrt() - 1.2)).abs() < 1e-12);
+        assert!(obb_obb(&straight, &Obb::from_quaternion(vec3(2.5, 0.0, 0.0), vec3(1.0, 1.0, 1.0), q)).is_none());
+        //An edge of the second box, along (1, -1, 0), faces the z edge of the first across the diagonal (1, 1, 0):
+        //only their cross product separates them
+        let (r, h) = (0.5_f64.sqrt(), 0.5);
+        let rotation = Matrix([[r, h, -h], [-r, h, -h], [0.0, r, r]]);
+        let gap = 0.05 * r;
+        let edge = Obb::new(vec3(2.0 + gap, 2.0 + gap, 0.0), vec3(1.0, 1.0, 1.0), rotation);
+        assert!(obb_obb(&straight, &edge).is_none());
+        assert!(obb_obb(&straight, &Obb { center: edge.center * 0.9, ..edge }).is_some());
+
+        let voxel: Aabb<3, f64> = Aabb::new(vec3(0.0, 0.0, 0.0), vec3(1.0, 1.0, 1.0));
+        let through = Triangle::new(vec3(-1.0, -1.0, 0.5), vec3(3.0, -1.0, 0.5), vec3(-1.0, 3.0, 0.5));
+        let contact = triangle_aabb(&through, &voxel).unwrap();
+        assert_eq!(contact.normal.z().abs(), 1.0);
+        assert_eq!(contact.depth, 0.5);
+        //Cuts off a corner of the voxel; moved past the corner, only its plane separates them
+        let corner = Triangle::new(vec3(1.6, 0.0, 0.0), vec3(0.0, 1.6, 0.0), vec3(0.0, 0.0, 1.6));
+        assert!(triangle_aabb(&corner, &voxel).is_some());
+        let beyond = Triangle::new(vec3(3.1, 0.0, 0.0), vec3(0.0, 3.1, 0.0), vec3(0.0, 0.0, 3.1));
+        assert!(triangle_aabb(&beyond, &voxel).is_none());
+        let skew = Triangle::new(vec3(1.2, 0.5, -1.0), vec3(2.0, -1.0, 2.0), vec3(2.0, 2.0, 2.0));
+        assert!(triangle_aabb(&skew, &voxel).is_none());
+        //The same configurations scaled down, where the unnormalized axes are far shorter than the tolerance
+        let tiny = |t: &Triangle<f64>| Triangle::new(t.a * 1e-5, t.b * 1e-5, t.c * 1e-5);
+        let tiny_voxel = Aabb::new(vec3(0.0, 0.0, 0.0), vec3(1e-5, 1e-5, 1e-5));
+        assert!(triangle_aabb(&tiny(&corner), &tiny_voxel).is_some());
+        assert!(triangle_aabb(&tiny(&beyond), &tiny_voxel).is_none());
+        assert!(triangle_aabb(&tiny(&skew), &tiny_voxel).is_none());
+
+        let floor = Triangle::new(vec3(-5.0, 0.0, -5.0), vec3(0.0, 0.0, 5.0), vec3(5.0, 0.0, -5.0));
+        let contact = sphere_triangle(&Sphere::new(vec3(0.0, 0.75, 0.0), 1.0), &floor).unwrap();
+        assert_eq!((contact.normal, contact.depth), (vec3(0.0, -1.0, 0.0), 0.25));
+        assert!(sphere_triangle(&Sphere::new(vec3(0.0, 1.25, 0.0), 1.0), &floor).is_none());
+        assert!(sphere_triangle(&Sphere::new(vec3(6.0, 0.5, 0.0), 1.0), &floor).is_none());
+    }
+
+    #[test]
+    fn frustum_culling() {
+        use crate::geometry::{Aabb, Containment, Frustum, Sphere};
+
+        //90° field of view looking down -z from the origin, so the side planes are at |x| = -z and |y| = -z
+        let perspective = Mat4::perspective(1.0, Angle::deg(90.0), 1.0, 100.0);
+        let frustum = Frustum::from_gl_matrix(&perspective);
+        assert_vec_close(frustum.planes[0].normal, vec3(1.0, 0.0, -1.0).normalized(), 1e-12);
+        assert_vec_close(frustum.planes[4].normal, vec3(0.0, 0.0, -1.0), 1e-12);
+        assert!((frustum.planes[4].distance - 1.0).abs() < 1e-12 && (frustum.planes[5].distance + 100.0).abs() < 1e-9);
+
+        assert_eq!(frustum.classify_point(vec3(0.0, 0.0, -10.0)), Containment::Inside);
+        assert_eq!(frustum.classify_point(vec3(9.0, -9.0, -10.0)), Containment::Inside);
+        assert_eq!(frustum.classify_point(vec3(11.0, 0.0, -10.0)), Containment::Outside);
+        assert_eq!(frustum.classify_point(vec3(0.0, 0.0, 10.0)), Containment::Outside);
+        assert_eq!(frustum.classify_point(vec3(0.0, 0.0, -0.5)), Containment::Outside);
+        assert_eq!(frustum.classify_point(vec3(0.0, 0.0, -101.0)), Containment::Outside);
+
+        assert_eq!(frustum.classify_sphere(&Sphere::new(vec3(0.0, 0.0, -50.0), 5.0)), Containment::Inside);
+        assert_eq!(frustum.classify_sphere(&Sphere::new(vec3(10.0, 0.0, -10.0), 1.0)), Containment::Intersecting);
+        assert_eq!(frustum.classify_sphere(&Sphere::new(vec3(0.0, 0.0, -100.5), 1.0)), Containment::Intersecting);
+        assert_eq!(frustum.classify_sphere(&Sphere::new(vec3(20.0, 0.0, -10.0), 1.0)), Containment::Outside);
+
+        assert_eq!(frustum.classify_aabb(&Aabb::new(vec3(-1.0, -1.0, -20.0), vec3(1.0, 1.0, -10.0))), Containment::Inside);
+        assert_eq!(frustum.classify_aabb(&Aabb::new(vec3(5.0, -1.0, -20.0), vec3(15.0, 1.0, -10.0))), Containment::Intersecting);
+        assert_eq!(frustum.classify_aabb(&Aabb::new(vec3(-1.0, -1.0, 5.0), vec3(1.0, 1.0, 10.0))), Containment::Outside);
+
+        //The same frustum seen from elsewhere
+        let view = Mat4::look_at(vec3(10.0, 0.0, 10.0), vec3(10.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0));
+        let moved = Frustum::from_gl_matrix(&(view * perspective));
+        assert_eq!(moved.classify_point(vec3(10.0, 0.0, 0.0)), Containment::Inside);
+        assert_eq!(moved.classify_point(vec3(0.0, 0.0, 0.0)), Containment::Outside);
+        assert_eq!(moved.classify_point(vec3(10.0, 0.0, 20.0)), Containment::Outside);
+
+        let orthographic = Mat4::orthographic(-2.0, 2.0, -1.0, 1.0, 1.0, 10.0);
+        let translated = orthographic * Mat4::translation(vec3(0.0, -5.0, 0.0));
+        let frustum = Frustum::from_matrix(&translated);
+        assert_eq!(frustum.classify_point(vec3(1.5, 5.5, -5.0)), Containment::Inside);
+        assert_eq!(frustum.classify_point(vec3(1.5, 0.5, -5.0)), Containment::Outside);
+        assert_eq!(frustum.classify_sphere(&Sphere::new(vec3(2.0, 5.0, -5.0), 0.5)), Containment::Intersecting);
+        assert_eq!(frustum.classify_aabb(&Aabb::new(vec3(-1.0, 4.5, -9.0), vec3(1.0, 5.5, -2.0))), Containment::Inside);
+
+        //A grid of boxes against the perspective frustum, compared with one at a time
+        let frustum = Frustum::from_gl_matrix(&perspective);
+        let mut boxes = Vec::new();
+        let mut spheres = Vec::new();
+        for i in -20..20 {
+            for j in 0..60 {
+                let center = vec3(i as f64 * 3.0, 0.5, -(j as f64) * 2.0);
+                boxes.push(Aabb::from_center_half_extents(center, vec3(1.0, 1.0, 1.0)));
+                spheres.push(Sphere::new(center, 1.0));
+            }
+        }
+        let mut classes = Vec::new();
+        frustum.classify_aabbs(&boxes, &mut classes);
+        assert_eq!(classes.len(), boxes.len());
+        assert!(classes.iter().zip(&boxes).all(|(c, b)| *c == frustum.classify_aabb(b)));
+        let count = |c: &[Containment], of: Containment| c.iter().filter(|x| **x == of).count();
+        assert!(count(&classes, Containment::Inside) > 0 && count(&classes, Containment::Outside) > 0);
+        assert!(count(&classes, Containment::Intersecting) > 0);
+
+        frustum.classify_spheres(&spheres, &mut classes);
+        assert_eq!(classes.len(), spheres.len());
+        for (class, sphere) in classes.iter().zip(&spheres) {
+            assert_eq!(*class, frustum.classify_sphere(sphere));
+            if *class == Containment::Inside {
+                assert!(frustum.contains(sphere.center));
+            }
+            if frustum.contains(sphere.center) {
+                assert_ne!(*class, Containment::Outside);
+            }
+        }
+    }
+
+    ///Deterministic pseudo-random numbers in `[0, 1)` for tests, from a 64-bit linear congruential generator.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self) -> f64 {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (self.0 >> 11) as f64 / (1u64 << 53) as f64
+        }
+
+        fn range(&mut self, low: f64, high: f64) -> f64 {
+            low + (high - low) * self.next()
+        }
+
+        fn vec3(&mut self, low: f64, high: f64) -> Vector3<f64> {
+            vec3(self.range(low, high), self.range(low, high), self.range(low, high))
+        }
+    }
+
+    #[test]
+    fn bounding_volume_fitting() {
+        use crate::geometry::{Aabb, Obb, Sphere, Transform};
+
+        let contains = |obb: &Obb<f64>, p: Vector3<f64>| obb.distance(p) <= 1e-9;
+        let mut rng = Lcg(7);
+
+        //An elongated box, turned and moved, sampled on a symmetric grid so its principal axes are exact
+        let q = Quaternion::from_axis_angle(vec3(1.0, 2.0, 3.0), Angle::deg(40.0));
+        let truth = Obb::from_quaternion(vec3(5.0, -3.0, 2.0), vec3(4.0, 2.0, 1.0), q);
+        let mut points = truth.corners().to_vec();
+        for i in -4..=4 {
+            for j in -2..=2 {
+                for k in -1..=1 {
+                    points.push(truth.from_local(vec3(i as f64, j as f64, k as f64)));
+                }
+            }
+        }
+        let pca = Obb::from_points_pca(&points).unwrap();
+        assert!(points.iter().all(|p| contains(&pca, *p)));
+        assert!((pca.volume() - truth.volume()).abs() < 1e-6 * truth.volume());
+        assert_vec_close(pca.center, truth.center, 1e-9);
+        assert!(Obb::<f64>::from_points_pca(&[]).is_none());
+
+        //A cube has isotropic covariance, so PCA picks arbitrary axes and the refinement has to find the cube
+        let q = Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Angle::deg(30.0)) * Quaternion::from_axis_angle(vec3(1.0, 0.0, 0.0), Angle::deg(20.0));
+        let cube = Obb::from_quaternion(vec3(0.0, 0.0, 0.0), vec3(1.0, 1.0, 1.0), q);
+        let corners = cube.corners();
+        let pca = Obb::from_points_pca(&corners).unwrap();
+        let refined = Obb::from_points_min_volume(&corners).unwrap();
+        assert!(corners.iter().all(|p| contains(&refined, *p)));
+        assert!(refined.volume() <= pca.volume());
+        assert!(refined.volume() < 8.0 * 1.01, "{}", refined.volume());
+
+        let aabb = Aabb::new(vec3(-1.0, 0.0, 2.0), vec3(3.0, 1.0, 4.0));
+        let obb: Obb<f64> = aabb.into();
+        assert_eq!(obb.to_aabb(), aabb);
+        let turned = obb.rotated(&Quaternion::from_axis_angle(vec3(0.0, 0.0, 1.0), Angle::deg(90.0)));
+        let expected = Aabb::new(vec3(-1.0, -1.0, 2.0), vec3(0.0, 3.0, 4.0));
+        assert_vec_close(turned.to_aabb().min, expected.min, 1e-12);
+        assert_vec_close(turned.to_aabb().max, expected.max, 1e-12);
+
+        let merged = truth.merge(&cube);
+        assert!(truth.corners().iter().chain(cube.corners().iter()).all(|p| contains(&merged, *p)));
+
+        let rigid = Mat4::translation(vec3(1.0, 2.0, 3.0)) * Mat4::rotation_y(Angle::deg(25.0));
+        let moved = truth.transformed(&rigid);
+        assert_vec_close(moved.half_extents, truth.half_extents, 1e-9);
+        assert_vec_close(moved.center, rigid.transform_point(truth.center), 1e-9);
+        let sheared = Mat4::translation(vec3(1.0, 2.0, 3.0)) * crate::functions::scale(vec3(3.0, 1.0, 0.5)) * Mat4::rotation_y(Angle::deg(25.0));
+        let stretched = truth.transformed(&sheared);
+        assert!(points.iter().all(|p| contains(&stretched, sheared.transform_point(*p))));
+
+        //The six axis points pin the minimal sphere; the others lie inside it
+        let center = vec3(1.0, -2.0, 0.5);
+        let mut cloud: Vec<Vector3<f64>> = [1.0, -1.0].iter().flat_map(|s| [vec3(*s, 0.0, 0.0), vec3(0.0, *s, 0.0), vec3(0.0, 0.0, *s)]).map(|d| center + d * 3.0).collect();
+        while cloud.len() < 300 {
+            let p = rng.vec3(-3.0, 3.0);
+            if p.magnitude() < 3.0 {
+                cloud.push(center + p);
+            }
+        }
+        let welzl = Sphere::from_points_welzl(&cloud).unwrap();
+        assert_vec_close(welzl.center, center, 1e-9);
+        assert!((welzl.radius - 3.0).abs() < 1e-9);
+        let ritter = Sphere::from_points_ritter(&cloud).unwrap();
+        assert!(cloud.iter().all(|p| ritter.center.distance(*p) <= ritter.radius + 1e-9));
+        assert!(ritter.radius >= welzl.radius - 1e-9 && ritter.radius < welzl.radius * 1.2);
+        let tiny: Vec<Vector3<f64>> = cloud.iter().map(|p| *p * 1e-9).collect();
+        let welzl = Sphere::from_points_welzl(&tiny).unwrap();
+        assert_vec_close(welzl.center, center * 1e-9, 1e-18);
+        assert!((welzl.radius - 3e-9).abs() < 1e-18);
+
+        //Regular tetrahedron: all four points on the sphere
+        let tetrahedron = [vec3(1.0, 1.0, 1.0), vec3(1.0, -1.0, -1.0), vec3(-1.0, 1.0, -1.0), vec3(-1.0, -1.0, 1.0)];
+        let sphere = Sphere::from_points_welzl(&tetrahedron).unwrap();
+        assert_vec_close(sphere.center, vec3(0.0, 0.0, 0.0), 1e-9);
+        assert!((sphere.radius - 3.0_f64.sqrt()).abs() < 1e-9);
+        //Collinear and coincident points
+        let line = Sphere::from_points_welzl(&[vec3(0.0, 0.0, 0.0), vec3(1.0, 0.0, 0.0), vec3(4.0, 0.0, 0.0)]).unwrap();
+        assert_eq!((line.center, line.radius), (vec3(2.0, 0.0, 0.0), 2.0));
+        assert_eq!(Sphere::from_points_welzl(&[vec3(1.0, 1.0, 1.0); 3]).unwrap().radius, 0.0);
+        let close = Sphere::from_points_welzl(&[vec3(1.0, 1.0, 1.0), vec3(1.0, 1.0, 1.0), vec3(1.0, 1.0, 1.0 + 1e-12)]).unwrap();
+        assert!(close.radius > 0.0);
+        assert!(Sphere::<f64>::from_points_welzl(&[]).is_none());
+    }
+
+    #[test]
+    fn gjk_epa() {
+        use crate::geometry::gjk::{epa, gjk_distance, gjk_intersects};
+        use crate::geometry::overlap::{aabb_aabb, obb_obb, sphere_sphere};
+        use crate::geometry::{Aabb, Capsule, MinkowskiSum, Obb, Sphere, SupportMap};
+
+        let a: Sphere<f64> = Sphere::new(vec3(0.0, 0.0, 0.0), 1.0);
+        let b = Sphere::new(vec3(3.0, 4.0, 0.0), 2.0);
+        let separation = gjk_distance(&a, &b).unwrap();
+        assert!((separation.distance - 2.0).abs() < 1e-6);
+        assert_vec_close(separation.point_a, vec3(0.6, 0.8, 0.0), 1e-4);
+        assert_vec_close(separation.point_b, vec3(1.8, 2.4, 0.0), 1e-4);
+
+        let boxed = Aabb::new(vec3(-1.0, -1.0, -1.0), vec3(1.0, 1.0, 1.0));
+        let other = Aabb::new(vec3(2.0, -0.5, 3.0), vec3(4.0, 0.5, 5.0));
+        let separation = gjk_distance(&boxed, &other).unwrap();
+        assert!((separation.distance - 5.0_f64.sqrt()).abs() < 1e-9);
+        assert_vec_close(separation.point_a - separation.point_b, vec3(-1.0, 0.0, -2.0), 1e-9);
+
+        let capsule: Capsule<f64> = Capsule::new(vec3(0.0, -5.0, 0.0), vec3(0.0, 5.0, 0.0), 0.5);
+        let separation = gjk_distance(&capsule, &Sphere::new(vec3(3.0, 1.0, 0.0), 1.0)).unwrap();
+        assert!((separation.distance - 1.5).abs() < 1e-6);
+
+        //A rounded box as the Minkowski sum of a box and a sphere, against a single point
+        let rounded = MinkowskiSum(boxed, Sphere::new(vec3(0.0, 0.0, 0.0), 0.5));
+        let point = vec![vec3(3.0, 3.0, 0.0)];
+        let separation = gjk_distance(&rounded, &point).unwrap();
+        assert!((separation.distance - (8.0_f64.sqrt() - 0.5)).abs() < 1e-6);
+        assert!(gjk_intersects(&rounded, &vec![vec3(1.4, 0.0, 0.0)]));
+        assert!(!gjk_intersects(&rounded, &vec![vec3(1.6, 0.0, 0.0)]));
+
+        //Convex hull of a tetrahedron's vertices
+        let hull = vec![vec3(0.0, 0.0, 0.0), vec3(1.0, 0.0, 0.0), vec3(0.0, 1.0, 0.0), vec3(0.0, 0.0, 1.0)];
+        assert_eq!(hull.support(vec3(1.0, 1.0, 2.0)), vec3(0.0, 0.0, 1.0));
+        let separation = gjk_distance(&hull, &Sphere::new(vec3(1.0, 1.0, 1.0), 0.1)).unwrap();
+        assert!((separation.distance - (2.0 / 3.0_f64.sqrt() - 0.1)).abs() < 1e-6);
+        assert!(epa(&hull, &Sphere::new(vec3(2.0, 2.0, 2.0), 0.1)).is_none());
+
+        //Penetration agrees with the closed-form tests
+        let c = Sphere::new(vec3(1.5, 0.0, 0.0), 1.0);
+        let expected = sphere_sphere(&a, &c).unwrap();
+        let contact = epa(&a, &c).unwrap();
+        assert!((contact.depth - expected.depth).abs() < 1e-3, "{:?}", contact);
+        assert!(contact.normal.dot_product(expected.normal) > 0.999);
+
+        let shifted = Aabb::new(vec3(0.7, -0.5, -0.5), vec3(2.0, 0.5, 0.5));
+        let expected = aabb_aabb(&boxed, &shifted).unwrap();
+        let contact = epa(&boxed, &shifted).unwrap();
+        assert!((contact.depth - expected.depth).abs() < 1e-9);
+        assert_vec_close(contact.normal, expected.normal, 1e-9);
+
+        let q = Quaternion::from_axis_angle(vec3(0.0, 1.0, 1.0), Angle::deg(30.0));
+        let turned = Obb::from_quaternion(vec3(0.3, 1.9, 0.0), vec3(1.0, 1.0, 1.0), q);
+        assert!(gjk_intersects(&boxed, &turned));
+        let contact = epa(&boxed, &turned).unwrap();
+        let expected = obb_obb(&Obb::from_aabb(&boxed), &turned).unwrap();
+        assert!((contact.depth - expected.depth).abs() < 1e-6);
+        let moved = Obb { center: turned.center + contact.normal * (contact.depth + 1e-6), ..turned };
+        assert!(!gjk_intersects(&boxed, &moved));
+        let moved = Obb { center: turned.center + contact.normal * (contact.depth - 1e-3), ..turned };
+        assert!(gjk_intersects(&boxed, &moved));
+
+        //Touching boxes intersect with no depth
+        let touching = Aabb::new(vec3(1.0, -1.0, -1.0), vec3(3.0, 1.0, 1.0));
+        assert!(gjk_intersects(&boxed, &touching));
+        assert!(epa(&boxed, &touching).unwrap().depth < 1e-9);
+
+        //The same penetrations scaled down, where absolute thresholds would stop EPA before it starts
+        let scale = 1e-6;
+        let tiny = |s: &Sphere<f64>| Sphere::new(s.center * scale, s.radius * scale);
+        let contact = epa(&tiny(&a), &tiny(&c)).unwrap();
+        assert!((contact.depth - 0.5 * scale).abs() < 1e-3 * scale, "{:?}", contact);
+        let tiny_turned = Obb { center: turned.center * scale, half_extents: turned.half_extents * scale, ..turned };
+        let tiny_box = Aabb::new(boxed.min * scale, boxed.max * scale);
+        let contact = epa(&tiny_box, &tiny_turned).unwrap();
+        let expected = obb_obb(&Obb::from_aabb(&tiny_box), &tiny_turned).unwrap();
+        assert!((contact.depth - expected.depth).abs() < 1e-6 * scale, "{:?} {:?}", contact, expected);
+    }
+
+    #[test]
+    fn convex_hulls() {
+        use crate::geometry::hull::convex_hull_2d;
+        use crate::geometry::{ConvexHull, SupportMap};
+
+        //Every directed edge appears once and its reverse once, and each face looks away from the inside
+        let check_closed = |hull: &ConvexHull<f64>| {
+            let mut edges = Vec::new();
+            for t in &hull.triangles {
+                for e in 0..3 {
+                    edges.push((t[e], t[(e + 1) % 3]));
+                }
+            }
+            for &(a, b) in &edges {
+                assert_eq!(edges.iter().filter(|e| **e == (a, b)).count(), 1);
+                assert_eq!(edges.iter().filter(|e| **e == (b, a)).count(), 1);
+            }
+            assert_eq!(hull.vertices.len() + hull.triangles.len(), edges.len() / 2 + 2);
+        };
+        let check_outward = |hull: &ConvexHull<f64>| {
+            let inside = hull.vertices.iter().fold(vec3(0.0, 0.0, 0.0), |sum, v| sum + *v) / hull.vertices.len() as f64;
+            for t in &hull.triangles {
+                let [a, b, c] = t.map(|i| hull.vertices[i]);
+                assert!((b - a).cross_product(c - a).dot_product(a - inside) > 0.0);
+            }
+        };
+
+        //Cube corners with points inside and on its faces
+        let mut rng = Lcg(11);
+        let mut points: Vec<Vector3<f64>> = (0..200).map(|_| rng.vec3(-1.0, 1.0)).collect();
+        for i in 0..8 {
+            points.push(vec3([-1.0, 1.0][i & 1], [-1.0, 1.0][(i >> 1) & 1], [-1.0, 1.0][i >> 2]));
+            points.push(vec3([-1.0, 1.0][i & 1], rng.range(-1.0, 1.0), rng.range(-1.0, 1.0)));
+        }
+        let hull = ConvexHull::new(&points);
+        assert_eq!(hull.vertices.len(), 8);
+        assert_eq!(hull.triangles.len(), 12);
+        check_closed(&hull);
+        check_outward(&hull);
+        assert!(points.iter().all(|p| hull.contains(*p)));
+        assert!(!hull.contains(vec3(1.01, 0.0, 0.0)));
+        assert_eq!(hull.support(vec3(1.0, -2.0, 3.0)), vec3(1.0, -1.0, 1.0));
+
+        //The tolerance follows the size of the input, not its distance from the origin
+        let offset = vec3(1000.0_f32, 1000.0, 1000.0);
+        let translated: Vec<Vector3<f32>> = points.iter().map(|p| vec3(p.x() as f32, p.y() as f32, p.z() as f32) * 0.5 + offset).collect();
+        let hull = ConvexHull::new(&translated);
+        assert_eq!((hull.vertices.len(), hull.triangles.len()), (8, 12));
+        assert!(hull.contains(offset) && !hull.contains(offset + vec3(0.6, 0.0, 0.0)));
+
+        //Points on a sphere are all on the hull
+        let sphere: Vec<Vector3<f64>> = (0..300).map(|_| (rng.vec3(-1.0, 1.0) + vec3(0.0, 0.0, 1e-3)).normalized() * 2.0 + vec3(5.0, 0.0, -3.0)).collect();
+        let hull = ConvexHull::new(&sphere);
+        assert_eq!(hull.vertices.len(), sphere.len());
+        check_closed(&hull);
+        check_outward(&hull);
+        assert!(sphere.iter().all(|p| hull.contains(*p)));
+
+        //Degenerate input
+        assert_eq!(ConvexHull::<f64>::new(&[]).vertices.len(), 0);
+        let single = ConvexHull::new(&[vec3(1.0, 2.0, 3.0); 4]);
+        assert_eq!((single.vertices, single.triangles.len()), (vec![vec3(1.0, 2.0, 3.0)], 0));
+        let line: Vec<Vector3<f64>> = (0..10).map(|i| vec3(1.0, 2.0, 3.0) * ((i * 7 % 10) as f64)).collect();
+        let segment = ConvexHull::new(&line);
+        assert_eq!(segment.triangles.len(), 0);
+        assert!(segment.vertices.contains(&vec3(0.0, 0.0, 0.0)) && segment.vertices.contains(&vec3(9.0, 18.0, 27.0)));
+
+        let q = Quaternion::from_axis_angle(vec3(1.0, 1.0, 0.0), Angle::deg(35.0));
+        let mut flat: Vec<Vector3<f64>> = (0..50).map(|_| q.rotate(vec3(rng.range(-1.0, 1.0), rng.range(-1.0, 1.0), 0.0))).collect();
+        flat.extend([vec3(-2.0, -2.0, 0.0), vec3(2.0, -2.0, 0.0), vec3(2.0, 2.0, 0.0), vec3(-2.0, 2.0, 0.0), vec3(0.0, 2.0, 0.0)].map(|p| q.rotate(p)));
+        let polygon = ConvexHull::new(&flat);
+        assert_eq!(polygon.vertices.len(), 4);
+        assert_eq!(polygon.triangles.len(), 4);
+        check_closed(&polygon);
+
+        //Monotone chain: counter-clockwise from the lowest point, without collinear or interior points
+        let mut square: Vec<Vector2<f64>> = (0..50).map(|_| vec2(rng.range(0.0, 1.0), rng.range(0.0, 1.0))).collect();
+        square.extend([vec2(1.0, 1.0), vec2(0.0, 1.0), vec2(0.5, 0.0), vec2(1.0, 0.0), vec2(0.0, 0.0), vec2(1.0, 0.0)]);
+        assert_eq!(convex_hull_2d(&square), vec![vec2(0.0, 0.0), vec2(1.0, 0.0), vec2(1.0, 1.0), vec2(0.0, 1.0)]);
+        assert_eq!(convex_hull_2d(&[vec2(2.0, 1.0), vec2(0.0, 0.0), vec2(1.0, 0.5), vec2(2.0, 1.0)]), vec![vec2(0.0, 0.0), vec2(2.0, 1.0)]);
+        assert_eq!(convex_hull_2d(&[vec2(3.0, 3.0); 3]), vec![vec2(3.0, 3.0)]);
+        assert!(convex_hull_2d::<f64>(&[]).is_empty());
+    }
+
+    #[test]
+    fn bvh_queries() {
+        use crate::geometry::{Aabb, Bvh, Ray, Sphere};
+
+        let mut rng = Lcg(5);
+        let bounds = |s: &Sphere<f64>| Aabb::from_center_half_extents(s.center, vec3(s.radius, s.radius, s.radius));
+        let mut spheres: Vec<Option<Sphere<f64>>> = (0..300).map(|_| Some(Sphere::new(rng.vec3(-20.0, 20.0), rng.range(0.2, 1.5)))).collect();
+        let all: Vec<_> = spheres.iter().map(|s| bounds(&s.unwrap())).collect();
+        let mut bvh = Bvh::new(&all);
+        assert_eq!(bvh.len(), 300);
+
+        //Every query agrees with testing each sphere in turn
+        let check = |bvh: &Bvh<f64>, spheres: &[Option<Sphere<f64>>], rng: &mut Lcg| {
+            for _ in 0..200 {
+                let ray = Ray::new(rng.vec3(-25.0, 25.0), rng.vec3(-1.0, 1.0));
+                let max = rng.range(5.0, 60.0);
+                let brute = spheres
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, s)| Some((i, ray.cast(&(*s)?)?)))
+                    .filter(|(_, h)| h.distance <= max)
+                    .min_by(|a, b| a.1.distance.partial_cmp(&b.1.distance).unwrap());
+                let hit = |i: usize| ray.cast(&spheres[i].unwrap());
+                let found = bvh.raycast(&ray, max, hit);
+                assert_eq!(found.map(|f| f.0), brute.map(|b| b.0));
+                let any = bvh.raycast_any(&ray, max, hit);
+                assert_eq!(any.is_some(), brute.is_some());
+                assert!(any.is_none_or(|(i, h)| h.distance <= max && ray.cast(&spheres[i].unwrap()) == Some(h)));
+
+                let query = Aabb::from_center_half_extents(rng.vec3(-20.0, 20.0), rng.vec3(0.0, 6.0));
+                let mut found = Vec::new();
+                bvh.query_aabb(&query, &mut found);
+                found.sort();
+                let expected: Vec<usize> = (0..spheres.len()).filter(|i| spheres[*i].is_some_and(|s| bounds(&s).intersects(&query))).collect();
+                assert_eq!(found, expected);
+            }
+        };
+        check(&bvh, &spheres, &mut rng);
+
+        //Everything moves, then refit
+        for s in spheres.iter_mut().flatten() {
+            s.center += rng.vec3(-2.0, 2.0);
+        }
+        bvh.refit(|i| bounds(&spheres[i].unwrap()));
+        check(&bvh, &spheres, &mut rng);
+
+        //Single moves, insertions and removals
+        for i in (0..300).step_by(7) {
+            let moved = Sphere::new(rng.vec3(-20.0, 20.0), 1.0);
+            spheres[i] = Some(moved);
+            assert!(bvh.update(i, bounds(&moved)));
+        }
+        for i in (0..300).step_by(3) {
+            assert!(bvh.remove(i));
+            spheres[i] = None;
+        }
+        assert!(!bvh.remove(0));
+        for _ in 0..100 {
+            let added = Sphere::new(rng.vec3(-30.0, 30.0), rng.range(0.2, 1.5));
+            assert_eq!(bvh.insert(bounds(&added)), spheres.len());
+            spheres.push(Some(added));
+        }
+        assert_eq!(bvh.len(), 300);
+        assert_eq!(bvh.item_bounds(3), None);
+        assert_eq!(bvh.item_bounds(4), Some(bounds(&spheres[4].unwrap())));
+        check(&bvh, &spheres, &mut rng);
+
+        //Emptied and refilled
+        for i in 0..spheres.len() {
+            bvh.remove(i);
+        }
+        assert!(bvh.is_empty() && bvh.bounds().is_none());
+        let ray = Ray::new(vec3(0.0, 0.0, 0.0), vec3(1.0, 0.0, 0.0));
+        assert!(bvh.raycast(&ray, 100.0, |_| unreachable!()).is_none());
+        let item = bvh.insert(Aabb::new(vec3(2.0, -1.0, -1.0), vec3(3.0, 1.0, 1.0)));
+        assert_eq!(bvh.raycast(&ray, 100.0, |_| Some(ray.cast(&bvh.item_bounds(item).unwrap()).unwrap())).unwrap().1.distance, 2.0);
+    }
+
+    #[test]
+    fn kdtree_queries() {
+        use crate::geometry::kdtree::{Chebyshev, Euclidean, Manhattan};
+        use crate::geometry::{Aabb, KdTree, Metric};
+
+        let mut rng = Lcg(3);
+        let mut points: Vec<Vector3<f64>> = (0..500).map(|_| rng.vec3(-10.0, 10.0)).collect();
+        //Duplicates and points sharing a coordinate with others
+        points.extend_from_within(..20);
+        points.extend((0..30).map(|i| vec3(1.0, i as f64 * 0.5 - 7.0, 2.0)));
+        let tree = KdTree::from_points(&points);
+        assert_eq!(tree.len(), points.len());
+
+        fn check<M: Metric<3, f64>>(tree: &KdTree<3, f64, usize>, points: &[Vector3<f64>], metric: &M, rng: &mut Lcg) {
+            for _ in 0..50 {
+                let query = rng.vec3(-12.0, 12.0);
+                let mut brute: Vec<(f64, usize)> = points.iter().enumerate().map(|(i, p)| (metric.distance(query, *p), i)).collect();
+                brute.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+                let k = 1 + (rng.next() * 12.0) as usize;
+                let nearest = tree.nearest_by(query, k, metric);
+                assert_eq!(nearest.len(), k);
+                for (n, b) in nearest.iter().zip(&brute) {
+                    assert_eq!(n.distance, b.0);
+                    assert_eq!(n.point, points[*n.item]);
+                    assert_eq!(metric.distance(query, n.point), n.distance);
+                }
+
+                let radius = rng.range(0.0, 6.0);
+                let mut within: Vec<usize> = tree.within_radius_by(query, radius, metric).iter().map(|n| *n.item).collect();
+                within.sort();
+                let mut expected: Vec<usize> = brute.iter().filter(|b| b.0 <= radius).map(|b| b.1).collect();
+                expected.sort();
+                assert_eq!(within, expected);
+            }
+        }
+        check(&tree, &points, &Euclidean, &mut rng);
+        check(&tree, &points, &Manhattan, &mut rng);
+        check(&tree, &points, &Chebyshev, &mut rng);
+
+        for _ in 0..50 {
+            let aabb = Aabb::new(rng.vec3(-12.0, 12.0), rng.vec3(-12.0, 12.0));
+            let mut found: Vec<usize> = tree.in_aabb(&aabb).iter().map(|e| e.1).collect();
+            found.sort();
+            let expected: Vec<usize> = (0..points.len()).filter(|i| aabb.contains(points[*i])).collect();
+            assert_eq!(found, expected);
+        }
+        let column = Aabb::new(vec3(1.0, -7.0, 2.0), vec3(1.0, 7.5, 2.0));
+        assert_eq!(tree.in_aabb(&column).len(), 30);
+
+        //Nearest first, and never more than there are
+        let nearest = tree.nearest(points[3], 3);
+        assert_eq!(nearest[0].distance, 0.0);
+        assert!(nearest.windows(2).all(|w| w[0].distance <= w[1].distance));
+        assert_eq!(tree.nearest(vec3(0.0, 0.0, 0.0), 10_000).len(), points.len());
+        assert!(tree.nearest(vec3(0.0, 0.0, 0.0), 0).is_empty());
+
+        //Two dimensions with named items
+        let cities = KdTree::new(&[(vec2(0.0, 0.0), "origin"), (vec2(3.0, 4.0), "far"), (vec2(1.0, 1.0), "near")]);
+        let found = cities.nearest(vec2(0.9, 0.8), 2);
+        assert_eq!(found.iter().map(|n| *n.item).collect::<Vec<_>>(), vec!["near", "origin"]);
+        assert_eq!(cities.within_radius(vec2(0.0, 0.0), 5.0).len(), 3);
+        assert_eq!(cities.within_radius_by(vec2(0.0, 0.0), 2.0, &Manhattan).len(), 2);
+        let empty: KdTree<2, f64, ()> = KdTree::new(&[]);
+        assert!(empty.is_empty() && empty.nearest(vec2(0.0, 0.0), 1).is_empty());
+    }
+
+    #[test]
+    fn polygons() {
+        use crate::geometry::polygon::simplify_polyline;
+        use crate::geometry::{Polygon2, Winding};
+
+        let square = Polygon2::new(vec![vec2(0.0, 0.0), vec2(2.0, 0.0), vec2(2.0, 2.0), vec2(0.0, 2.0)]);
+        assert_eq!(square.signed_area(), 4.0);
+        assert_eq!(square.reversed().signed_area(), -4.0);
+        assert_eq!(square.winding(), Some(Winding::CounterClockwise));
+        assert_eq!(square.reversed().winding(), Some(Winding::Clockwise));
+        assert_eq!(square.centroid(), Some(vec2(1.0, 1.0)));
+        assert_eq!(Polygon2::new(vec![vec2(0.0, 0.0), vec2(1.0, 1.0), vec2(2.0, 2.0)]).winding(), None);
+
+        let l_shape = Polygon2::new(vec![vec2(0.0, 0.0), vec2(2.0, 0.0), vec2(2.0, 1.0), vec2(1.0, 1.0), vec2(1.0, 2.0), vec2(0.0, 2.0)]);
+        assert_eq!(l_shape.area(), 3.0);
+        assert_vec_close(l_shape.reversed().centroid().unwrap(), vec2(5.0 / 6.0, 5.0 / 6.0), 1e-12);
+
+        //Convexity, including a straight corner and a star that turns the same way at every corner
+        let star: Polygon2<f64> = Polygon2::new((0..5).map(|i| {
+            let (s, c) = (i as f64 * 4.0 * std::f64::consts::PI / 5.0).sin_cos();
+            vec2(c, s)
+        }).collect());
+        assert!(square.is_convex() && square.reversed().is_convex());
+        assert!(Polygon2::new(vec![vec2(0.0, 0.0), vec2(1.0, 0.0), vec2(2.0, 0.0), vec2(2.0, 2.0)]).is_convex());
+        assert!(!l_shape.is_convex());
+        assert!(!star.is_convex());
+        assert!(!Polygon2::new(vec![vec2(0.0, 0.0), vec2(1.0, 0.0)]).is_convex());
+
+        //Winding numbers and containment
+        assert_eq!(star.winding_number(vec2(0.0, 0.0)), 2);
+        assert_eq!(star.winding_number(vec2(0.5, 0.0)), 1);
+        assert_eq!(square.reversed().winding_number(vec2(1.0, 1.0)), -1);
+        assert!(l_shape.contains(vec2(0.5, 1.5)) && l_shape.contains(vec2(1.5, 1.0)) && l_shape.contains(vec2(0.0, 0.0)));
+        assert!(!l_shape.contains(vec2(1.5, 1.5)) && !l_shape.contains(vec2(-0.1, 1.0)));
+        let tiny = Polygon2::new(l_shape.vertices.iter().map(|v| *v * 1e-9).collect());
+        assert!(tiny.contains(vec2(0.5e-9, 1.5e-9)) && !tiny.contains(vec2(1.5e-9, 1.5e-9)));
+
+        //Triangulations cover the area with counter-clockwise triangles
+        let check = |outer: &Polygon2<f64>, holes: &[Polygon2<f64>], expected: usize| {
+            let triangles = outer.triangulate(holes).unwrap();
+            let points: Vec<Vector2<f64>> = outer.vertices.iter().chain(holes.iter().flat_map(|h| &h.vertices)).copied().collect();
+            assert_eq!(triangles.len(), expected);
+            let mut area = 0.0;
+            for t in &triangles {
+                let triangle = Polygon2::new(t.map(|i| points[i]).to_vec());
+                assert!(triangle.signed_area() > 0.0);
+                area += triangle.area();
+                let center = triangle.centroid().unwrap();
+                assert!(outer.contains(center) && holes.iter().all(|h| !h.contains(center)));
+            }
+            let expected_area = outer.area() - holes.iter().map(|h| h.area()).sum::<f64>();
+            assert!((area - expected_area).abs() < 1e-9, "{} {}", area, expected_area);
+        };
+        check(&l_shape, &[], 4);
+        check(&square, &[], 2);
+        let frame = Polygon2::new(vec![vec2(0.0, 0.0), vec2(0.0, 4.0), vec2(4.0, 4.0), vec2(4.0, 0.0)]);
+        let window = Polygon2::new(vec![vec2(1.0, 1.0), vec2(2.0, 1.0), vec2(2.0, 2.0), vec2(1.0, 2.0)]);
+        check(&frame, std::slice::from_ref(&window), 8);
+        let wide = Polygon2::new(vec![vec2(0.0, 0.0), vec2(10.0, 0.0), vec2(10.0, 4.0), vec2(0.0, 4.0)]);
+        let diamond = Polygon2::new(vec![vec2(6.0, 1.0), vec2(7.0, 2.0), vec2(6.0, 3.0), vec2(5.0, 2.0)]);
+        check(&wide, &[window.clone(), diamond.reversed()], 14);
+
+        let mut rng = Lcg(9);
+        let blob = Polygon2::new((0..40).map(|i| {
+            let (s, c) = (i as f64 * std::f64::consts::PI / 20.0).sin_cos();
+            vec2(c, s) * rng.range(1.0, 3.0)
+        }).collect());
+        check(&blob, &[], 38);
+
+        //Holes that cannot be bridged to the polygon
+        let outside = Polygon2::new(vec![vec2(5.0, 5.0), vec2(6.0, 5.0), vec2(6.0, 6.0)]);
+        assert_eq!(frame.triangulate(&[outside]), None);
+        let crossing = Polygon2::new(vec![vec2(3.0, 1.0), vec2(5.0, 1.0), vec2(5.0, 3.0), vec2(3.0, 3.0)]);
+        assert_eq!(frame.triangulate(&[window.clone(), crossing]), None);
+        check(&blob, &[Polygon2::new(vec![vec2(-0.5, -0.5), vec2(0.5, -0.5), vec2(0.0, 0.5)])], 43);
+
+        //Degenerate holes are not bridged, but still shift the indices of the holes after them
+        let slit = Polygon2::new(vec![vec2(3.0, 0.5), vec2(3.0, 3.5)]);
+        check(&frame, &[slit, window.clone()], 8);
+
+        //Offsetting grows or shrinks the edges whatever the winding
+        let grown = square.offset(0.5);
+        assert!((grown.area() - 9.0).abs() < 1e-12);
+        assert_vec_close(grown.vertices[0], vec2(-0.5, -0.5), 1e-12);
+        assert!((square.reversed().offset(0.5).area() - 9.0).abs() < 1e-12);
+        assert!((square.offset(-0.5).area() - 1.0).abs() < 1e-12);
+        let inset = l_shape.offset(-0.25);
+        assert!(inset.vertices.iter().all(|v| l_shape.contains(*v)));
+        assert!(l_shape.vertices.iter().all(|v| l_shape.offset(0.25).contains(*v)));
+
+        //Simplification drops collinear vertices and stays within the tolerance of the original
+        let mut dense = Vec::new();
+        for (a, b) in square.edges() {
+            dense.extend((0..10).map(|i| a.lerp(b, i as f64 / 10.0)));
+        }
+        let simplified = Polygon2::new(dense).simplify(1e-9);
+        assert_eq!(simplified.vertices.len(), 4);
+        assert!(square.vertices.iter().all(|v| simplified.vertices.contains(v)));
+
+        let noisy = Polygon2::new((0..200).map(|i| {
+            let (s, c) = (i as f64 * std::f64::consts::PI / 100.0).sin_cos();
+            vec2(c, s) * (5.0 + rng.range(-0.01, 0.01))
+        }).collect());
+        let simplified = noisy.simplify(0.1);
+        assert!(simplified.vertices.len() < 40 && simplified.vertices.len() > 4);
+        for v in &noisy.vertices {
+            let distance = simplified.edges().map(|(a, b)| {
+                let t = ((*v - a).dot_product(b - a) / (b - a).magnitude_squared()).clamp(0.0, 1.0);
+                v.distance(a + (b - a) * t)
+            }).fold(f64::INFINITY, f64::min);
+            assert!(distance <= 0.1 + 1e-12);
+        }
+        assert_eq!(simplify_polyline(&[vec2(0.0, 0.0), vec2(1.0, 0.001), vec2(2.0, 0.0)], 0.01), vec![vec2(0.0, 0.0), vec2(2.0, 0.0)]);
+    }
+}
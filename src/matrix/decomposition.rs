@@ -0,0 +1,372 @@
+use crate::functions::{mat_identity_fill, mat_mul, mat_transpose};
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use crate::vector::Vector;
+
+const SVD_MAX_SWEEPS: usize = 64;
+
+///Full Householder QR decomposition <i>A = QR</i>, where <i>Q</i> is orthogonal (M×M) and <i>R</i> is upper triangular (M×N).
+#[derive(Debug, Clone, Copy)]
+pub struct Qr<const M: usize, const N: usize, S: Scalar> {
+    pub q: Matrix<M, M, S>,
+    pub r: Matrix<M, N, S>,
+}
+
+///Cholesky decomposition <i>A = LL<sup>T</sup></i> of a symmetric positive-definite matrix.
+#[derive(Debug, Clone, Copy)]
+pub struct Cholesky<const L: usize, S: Scalar> {
+    pub l: Matrix<L, L, S>,
+}
+
+///Singular value decomposition <i>A = UΣV<sup>T</sup></i>.
+///The singular values in `sigma` are sorted in descending order and the columns of `u` and `v` follow them.
+#[derive(Debug, Clone, Copy)]
+pub struct Svd<const M: usize, const N: usize, S: Scalar> {
+    pub u: Matrix<M, N, S>,
+    pub sigma: Vector<N, S>,
+    pub v: Matrix<N, N, S>,
+}
+
+#[allow(clippy::needless_range_loop)]
+pub fn qr<const M: usize, const N: usize, S: Scalar>(a: Matrix<M, N, S>) -> Qr<M, N, S> {
+    let two = S::from_f32(2.0);
+    let mut r = a;
+    let mut q: Matrix<M, M, S> = mat_identity_fill(S::ONE);
+
+    for k in 0..M.min(N) {
+        let mut norm = S::ZERO;
+        for i in k..M {
+            norm += r.0[i][k] * r.0[i][k];
+        }
+        let norm = norm.square_root();
+        if norm == S::ZERO {
+            continue;
+        }
+
+        let alpha = if r.0[k][k] > S::ZERO { -norm } else { norm };
+        let mut v = [S::ZERO; M];
+        for i in k..M {
+            v[i] = r.0[i][k];
+        }
+        v[k] -= alpha;
+
+        let mut v_dot_v = S::ZERO;
+        for i in k..M {
+            v_dot_v += v[i] * v[i];
+        }
+        //alpha has the opposite sign of r[k][k], so |v[k]| >= norm and v is never zero here
+        if v_dot_v == S::ZERO {
+            continue;
+        }
+
+        //R = H R
+        for j in 0..N {
+            let mut dot = S::ZERO;
+            for i in k..M {
+                dot += v[i] * r.0[i][j];
+            }
+            let f = two * dot / v_dot_v;
+            for i in k..M {
+                r.0[i][j] -= f * v[i];
+            }
+        }
+
+        //Q = Q H
+        for i in 0..M {
+            let mut dot = S::ZERO;
+            for l in k..M {
+                dot += q.0[i][l] * v[l];
+            }
+            let f = two * dot / v_dot_v;
+            for l in k..M {
+                q.0[i][l] -= f * v[l];
+            }
+        }
+
+        for i in k + 1..M {
+            r.0[i][k] = S::ZERO;
+        }
+    }
+
+    Qr { q, r }
+}
+
+pub fn cholesky<const L: usize, S: Scalar>(a: Matrix<L, L, S>) -> Option<Cholesky<L, S>> {
+    let mut l: Matrix<L, L, S> = Matrix::ZERO;
+
+    for j in 0..L {
+        let mut d = a.0[j][j];
+        for k in 0..j {
+            d -= l.0[j][k] * l.0[j][k];
+        }
+        if d <= S::ZERO {
+            return None;
+        }
+        let d = d.square_root();
+        l.0[j][j] = d;
+
+        for i in j + 1..L {
+            let mut s = a.0[i][j];
+            for k in 0..j {
+                s -= l.0[i][k] * l.0[j][k];
+            }
+            l.0[i][j] = s / d;
+        }
+    }
+
+    Some(Cholesky { l })
+}
+
+///One-sided Jacobi SVD. Columns of `u` belonging to zero singular values are completed to an orthonormal set where M allows it.
+pub fn svd<const M: usize, const N: usize, S: Scalar>(a: Matrix<M, N, S>) -> Svd<M, N, S> {
+    let (one, two) = (S::ONE, S::from_f32(2.0));
+    let mut u = a;
+    let mut v: Matrix<N, N, S> = mat_identity_fill(one);
+
+    for _ in 0..SVD_MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..N {
+            for q in p + 1..N {
+                let (mut alpha, mut beta, mut gamma) = (S::ZERO, S::ZERO, S::ZERO);
+                for i in 0..M {
+                    alpha += u.0[i][p] * u.0[i][p];
+                    beta += u.0[i][q] * u.0[i][q];
+                    gamma += u.0[i][p] * u.0[i][q];
+                }
+                if gamma.absolute() <= S::EPSILON * (alpha * beta).square_root() || gamma == S::ZERO {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (two * gamma);
+                let sign = if zeta < S::ZERO { -one } else { one };
+                let t = sign / (zeta.absolute() + (one + zeta * zeta).square_root());
+                let c = one / (one + t * t).square_root();
+                let s = c * t;
+
+                for i in 0..M {
+                    let (up, uq) = (u.0[i][p], u.0[i][q]);
+                    u.0[i][p] = c * up - s * uq;
+                    u.0[i][q] = s * up + c * uq;
+                }
+                for i in 0..N {
+                    let (vp, vq) = (v.0[i][p], v.0[i][q]);
+                    v.0[i][p] = c * vp - s * vq;
+                    v.0[i][q] = s * vp + c * vq;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut sigma: Vector<N, S> = Vector::ZERO;
+    for j in 0..N {
+        let mut norm = S::ZERO;
+        for i in 0..M {
+            norm += u.0[i][j] * u.0[i][j];
+        }
+        sigma.0[j] = norm.square_root();
+    }
+
+    //Selection sort, descending, permuting the columns of U and V alongside.
+    for j in 0..N {
+        let mut largest = j;
+        for k in j + 1..N {
+            if sigma.0[k] > sigma.0[largest] {
+                largest = k;
+            }
+        }
+        if largest != j {
+            sigma.0.swap(j, largest);
+            for i in 0..M {
+                u.0[i].swap(j, largest);
+            }
+            for i in 0..N {
+                v.0[i].swap(j, largest);
+            }
+        }
+    }
+
+    let tolerance = sigma_tolerance::<M, N, S>(&sigma);
+    for j in 0..N {
+        if sigma.0[j] > tolerance {
+            for i in 0..M {
+                u.0[i][j] /= sigma.0[j];
+            }
+        } else {
+            sigma.0[j] = S::ZERO;
+            complete_column(&mut u, j);
+        }
+    }
+
+    Svd { u, sigma, v }
+}
+
+fn sigma_tolerance<const M: usize, const N: usize, S: Scalar>(sigma: &Vector<N, S>) -> S {
+    let largest = if N > 0 { sigma.0[0] } else { S::ZERO };
+    S::from_f32(M.max(N) as f32) * S::EPSILON * largest
+}
+
+///Replaces column `j` of `u` with a unit vector orthogonal to the columns before it, if one exists.
+#[allow(clippy::needless_range_loop)]
+fn complete_column<const M: usize, const N: usize, S: Scalar>(u: &mut Matrix<M, N, S>, j: usize) {
+    for i in 0..M {
+        u.0[i][j] = S::ZERO;
+    }
+    if j >= M {
+        return;
+    }
+    for e in 0..M {
+        let mut candidate = [S::ZERO; M];
+        candidate[e] = S::ONE;
+        for k in 0..j {
+            let mut dot = S::ZERO;
+            for i in 0..M {
+                dot += u.0[i][k] * candidate[i];
+            }
+            for i in 0..M {
+                candidate[i] -= dot * u.0[i][k];
+            }
+        }
+        let norm = candidate.iter().map(|c| *c * *c).sum::<S>().square_root();
+        if norm > S::from_f32(0.5) {
+            for i in 0..M {
+                u.0[i][j] = candidate[i] / norm;
+            }
+            return;
+        }
+    }
+}
+
+impl<const M: usize, const N: usize, S: Scalar> Qr<M, N, S> {
+    ///Least-squares solution of <i>Ax = b</i>. Returns `None` if <i>A</i> does not have full column rank.
+    pub fn solve(&self, b: Vector<M, S>) -> Option<Vector<N, S>> {
+        if M < N {
+            return None;
+        }
+        let qt_b = crate::functions::mat_mul_vec(mat_transpose(self.q), b);
+
+        let mut scale = S::ZERO;
+        for i in 0..N {
+            if self.r.0[i][i].absolute() > scale {
+                scale = self.r.0[i][i].absolute();
+            }
+        }
+        let tolerance = S::from_f32(M as f32) * S::EPSILON * scale;
+
+        let mut x: Vector<N, S> = Vector::ZERO;
+        for i in (0..N).rev() {
+            let d = self.r.0[i][i];
+            if d.absolute() <= tolerance || d == S::ZERO {
+                return None;
+            }
+            let mut s = qt_b.0[i];
+            for j in i + 1..N {
+                s -= self.r.0[i][j] * x.0[j];
+            }
+            x.0[i] = s / d;
+        }
+        Some(x)
+    }
+}
+
+impl<const L: usize, S: Scalar> Cholesky<L, S> {
+    pub fn solve(&self, b: Vector<L, S>) -> Vector<L, S> {
+        let l = &self.l;
+        let mut y: Vector<L, S> = Vector::ZERO;
+        for i in 0..L {
+            let mut s = b.0[i];
+            for k in 0..i {
+                s -= l.0[i][k] * y.0[k];
+            }
+            y.0[i] = s / l.0[i][i];
+        }
+
+        let mut x: Vector<L, S> = Vector::ZERO;
+        for i in (0..L).rev() {
+            let mut s = y.0[i];
+            for k in i + 1..L {
+                s -= l.0[k][i] * x.0[k];
+            }
+            x.0[i] = s / l.0[i][i];
+        }
+        x
+    }
+
+    pub fn determinant(&self) -> S {
+        let mut d = S::ONE;
+        for i in 0..L {
+            d *= self.l.0[i][i];
+        }
+        d * d
+    }
+}
+
+impl<const M: usize, const N: usize, S: Scalar> Svd<M, N, S> {
+    pub fn rank(&self) -> usize {
+        self.sigma.0.iter().filter(|s| **s > S::ZERO).count()
+    }
+
+    pub fn pseudo_inverse(&self) -> Matrix<N, M, S> {
+        let mut v_sigma = self.v;
+        for j in 0..N {
+            let s = self.sigma.0[j];
+            let inverse = if s > S::ZERO { S::ONE / s } else { S::ZERO };
+            for i in 0..N {
+                v_sigma.0[i][j] *= inverse;
+            }
+        }
+        mat_mul(v_sigma, mat_transpose(self.u))
+    }
+}
+
+impl<const L: usize, S: Scalar> Svd<L, L, S> {
+    ///Polar decomposition <i>A = RP</i>, where <i>R</i> is orthogonal and <i>P</i> is symmetric positive semi-definite.
+    pub fn polar(&self) -> (Matrix<L, L, S>, Matrix<L, L, S>) {
+        let r = mat_mul(self.u, mat_transpose(self.v));
+
+        let mut v_sigma = self.v;
+        for j in 0..L {
+            for i in 0..L {
+                v_sigma.0[i][j] *= self.sigma.0[j];
+            }
+        }
+        let p = mat_mul(v_sigma, mat_transpose(self.v));
+        (r, p)
+    }
+}
+
+impl<const M: usize, const N: usize, S: Scalar> Matrix<M, N, S> {
+    pub fn qr(self) -> Qr<M, N, S> {
+        qr(self)
+    }
+
+    pub fn svd(self) -> Svd<M, N, S> {
+        svd(self)
+    }
+
+    ///Least-squares solution of <i>Ax = b</i> via QR. Returns `None` if the matrix does not have full column rank.
+    pub fn solve(self, b: Vector<M, S>) -> Option<Vector<N, S>> {
+        qr(self).solve(b)
+    }
+
+    pub fn pseudo_inverse(self) -> Matrix<N, M, S> {
+        svd(self).pseudo_inverse()
+    }
+
+    pub fn rank(self) -> usize {
+        svd(self).rank()
+    }
+}
+
+impl<const L: usize, S: Scalar> Matrix<L, L, S> {
+    pub fn cholesky(self) -> Option<Cholesky<L, S>> {
+        cholesky(self)
+    }
+
+    pub fn polar(self) -> (Matrix<L, L, S>, Matrix<L, L, S>) {
+        svd(self).polar()
+    }
+}
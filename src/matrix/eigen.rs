@@ -0,0 +1,247 @@
+use crate::functions::mat_identity_fill;
+use crate::matrix::mat2::Mat2;
+use crate::matrix::mat3::Mat3;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector3};
+
+const JACOBI_MAX_SWEEPS: usize = 64;
+
+///Eigen-decomposition <i>A = VΛV<sup>T</sup></i> of a symmetric matrix.
+///Eigenvalues are sorted in descending order and `vectors` holds the matching orthonormal eigenvectors as columns.
+#[derive(Debug, Clone, Copy)]
+pub struct SymmetricEigen<const L: usize, S: Scalar> {
+    pub values: Vector<L, S>,
+    pub vectors: Matrix<L, L, S>,
+}
+
+impl<const L: usize, S: Scalar> SymmetricEigen<L, S> {
+    pub fn vector(&self, i: usize) -> Vector<L, S> {
+        let mut out: Vector<L, S> = Vector::ZERO;
+        for j in 0..L {
+            out.0[j] = self.vectors.0[j][i];
+        }
+        out
+    }
+
+    fn sorted(mut self) -> Self {
+        for j in 0..L {
+            let mut largest = j;
+            for k in j + 1..L {
+                if self.values.0[k] > self.values.0[largest] {
+                    largest = k;
+                }
+            }
+            if largest != j {
+                self.values.0.swap(j, largest);
+                for i in 0..L {
+                    self.vectors.0[i].swap(j, largest);
+                }
+            }
+        }
+        self
+    }
+}
+
+///Cyclic Jacobi eigenvalue iteration. Only the upper triangle of `a` is assumed to be meaningful.
+pub fn symmetric_eigen_jacobi<const L: usize, S: Scalar>(a: Matrix<L, L, S>) -> SymmetricEigen<L, S> {
+    let one = S::ONE;
+    let mut a = a;
+    for i in 0..L {
+        for j in 0..i {
+            a.0[i][j] = a.0[j][i];
+        }
+    }
+    let mut v: Matrix<L, L, S> = mat_identity_fill(one);
+
+    let mut scale = S::ZERO;
+    for i in 0..L {
+        for j in 0..L {
+            scale += a.0[i][j] * a.0[i][j];
+        }
+    }
+    let tolerance = S::EPSILON * S::EPSILON * scale;
+
+    for _ in 0..JACOBI_MAX_SWEEPS {
+        let mut off = S::ZERO;
+        for p in 0..L {
+            for q in p + 1..L {
+                off += a.0[p][q] * a.0[p][q];
+            }
+        }
+        if off <= tolerance {
+            break;
+        }
+
+        for p in 0..L {
+            for q in p + 1..L {
+                let apq = a.0[p][q];
+                if apq == S::ZERO {
+                    continue;
+                }
+                let theta = (a.0[q][q] - a.0[p][p]) / (S::from_f32(2.0) * apq);
+                let sign = if theta < S::ZERO { -one } else { one };
+                let t = sign / (theta.absolute() + (theta * theta + one).square_root());
+                let c = one / (t * t + one).square_root();
+                let s = t * c;
+
+                for k in 0..L {
+                    let (akp, akq) = (a.0[k][p], a.0[k][q]);
+                    a.0[k][p] = c * akp - s * akq;
+                    a.0[k][q] = s * akp + c * akq;
+                }
+                for k in 0..L {
+                    let (apk, aqk) = (a.0[p][k], a.0[q][k]);
+                    a.0[p][k] = c * apk - s * aqk;
+                    a.0[q][k] = s * apk + c * aqk;
+                }
+                for k in 0..L {
+                    let (vkp, vkq) = (v.0[k][p], v.0[k][q]);
+                    v.0[k][p] = c * vkp - s * vkq;
+                    v.0[k][q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    let mut values: Vector<L, S> = Vector::ZERO;
+    for i in 0..L {
+        values.0[i] = a.0[i][i];
+    }
+
+    SymmetricEigen { values, vectors: v }.sorted()
+}
+
+///Closed-form eigen-decomposition of a symmetric 2×2 matrix.
+pub fn symmetric_eigen2<S: Scalar>(a: Mat2<S>) -> SymmetricEigen<2, S> {
+    let (m00, m01, m11) = (a.0[0][0], a.0[0][1], a.0[1][1]);
+    let half = S::from_f32(0.5);
+
+    let theta = half * (S::from_f32(2.0) * m01).inv_tangent2(m00 - m11);
+    let (sin, cos) = theta.sine_cosine();
+
+    let first = m00 * cos * cos + S::from_f32(2.0) * m01 * cos * sin + m11 * sin * sin;
+    let second = m00 * sin * sin - S::from_f32(2.0) * m01 * cos * sin + m11 * cos * cos;
+
+    SymmetricEigen {
+        values: Vector([first, second]),
+        vectors: Matrix([
+                            [cos, -sin],
+                            [sin, cos],
+                        ]),
+    }.sorted()
+}
+
+///Closed-form eigen-decomposition of a symmetric 3×3 matrix.
+///The eigenvalues come from the trigonometric solution of the characteristic cubic, the eigenvector of the
+///best separated eigenvalue from cross products of the rows of <i>A - λI</i>, and the remaining pair from a 2×2 solve in
+///its orthogonal complement, so repeated eigenvalues are handled.
+pub fn symmetric_eigen3<S: Scalar>(a: Mat3<S>) -> SymmetricEigen<3, S> {
+    let (zero, one, two, three) = (S::ZERO, S::ONE, S::from_f32(2.0), S::from_f32(3.0));
+    let (a00, a01, a02, a11, a12, a22) = (a.0[0][0], a.0[0][1], a.0[0][2], a.0[1][1], a.0[1][2], a.0[2][2]);
+
+    let p1 = a01 * a01 + a02 * a02 + a12 * a12;
+    let q = (a00 + a11 + a22) / three;
+    let (b00, b11, b22) = (a00 - q, a11 - q, a22 - q);
+    let p2 = b00 * b00 + b11 * b11 + b22 * b22 + two * p1;
+    let p = (p2 / S::from_f32(6.0)).square_root();
+
+    if p <= S::EPSILON * q.absolute() || p == zero {
+        //A is a multiple of the identity
+        return SymmetricEigen {
+            values: Vector([a00, a11, a22]),
+            vectors: mat_identity_fill(one),
+        }.sorted();
+    }
+
+    let det = b00 * (b11 * b22 - a12 * a12) - a01 * (a01 * b22 - a12 * a02) + a02 * (a01 * a12 - b11 * a02);
+    let mut r = det / (two * p * p * p);
+    if r < -one {
+        r = -one;
+    } else if r > one {
+        r = one;
+    }
+    let phi = r.inv_cosine() / three;
+    let largest = q + two * p * phi.cosine();
+    let smallest = q + two * p * (phi + two * S::PI / three).cosine();
+    let middle = three * q - largest - smallest;
+
+    let distinct = if largest - middle >= middle - smallest { largest } else { smallest };
+
+    let rows = [
+        Vector([a00 - distinct, a01, a02]),
+        Vector([a01, a11 - distinct, a12]),
+        Vector([a02, a12, a22 - distinct]),
+    ];
+    let mut w = Vector3::ZERO;
+    let mut w_len = zero;
+    for (i, j) in [(0, 1), (0, 2), (1, 2)] {
+        let c: Vector3<S> = rows[i].cross_product(rows[j]);
+        let len = c.dot_product(c);
+        if len > w_len {
+            w = c;
+            w_len = len;
+        }
+    }
+    if w_len == zero {
+        return symmetric_eigen_jacobi(a);
+    }
+    let w = w / w_len.square_root();
+
+    //Orthonormal basis u, v of the complement of w
+    let helper = if w.x().absolute() > w.y().absolute() { Vector3::UP } else { Vector3::RIGHT };
+    let u = w.cross_product(helper).normalized();
+    let v = w.cross_product(u);
+
+    let au = a * u;
+    let av = a * v;
+    let reduced = symmetric_eigen2(Matrix([
+                                               [u.dot_product(au), u.dot_product(av)],
+                                               [u.dot_product(av), v.dot_product(av)],
+                                           ]));
+
+    let mut vectors: Mat3<S> = Matrix::ZERO;
+    for i in 0..3 {
+        vectors.0[i][0] = w.0[i];
+        vectors.0[i][1] = reduced.vectors.0[0][0] * u.0[i] + reduced.vectors.0[1][0] * v.0[i];
+        vectors.0[i][2] = reduced.vectors.0[0][1] * u.0[i] + reduced.vectors.0[1][1] * v.0[i];
+    }
+
+    SymmetricEigen {
+        values: Vector([distinct, reduced.values.0[0], reduced.values.0[1]]),
+        vectors,
+    }.sorted()
+}
+
+impl<const L: usize, S: Scalar> Matrix<L, L, S> {
+    ///Eigen-decomposition of a symmetric matrix. 2×2 and 3×3 matrices use closed-form solutions, larger ones Jacobi iteration.
+    pub fn symmetric_eigen(self) -> SymmetricEigen<L, S> {
+        if L == 2 {
+            let mut m: Mat2<S> = Matrix::ZERO;
+            copy_square(&self.0, &mut m.0);
+            let e = symmetric_eigen2(m);
+            let mut out = SymmetricEigen { values: Vector::ZERO, vectors: Matrix::ZERO };
+            copy_square(&e.vectors.0, &mut out.vectors.0);
+            out.values.0.copy_from_slice(&e.values.0);
+            out
+        } else if L == 3 {
+            let mut m: Mat3<S> = Matrix::ZERO;
+            copy_square(&self.0, &mut m.0);
+            let e = symmetric_eigen3(m);
+            let mut out = SymmetricEigen { values: Vector::ZERO, vectors: Matrix::ZERO };
+            copy_square(&e.vectors.0, &mut out.vectors.0);
+            out.values.0.copy_from_slice(&e.values.0);
+            out
+        } else {
+            symmetric_eigen_jacobi(self)
+        }
+    }
+}
+
+fn copy_square<const A: usize, const B: usize, S: Scalar>(from: &[[S; A]; A], to: &mut [[S; B]; B]) {
+    for i in 0..A.min(B) {
+        for j in 0..A.min(B) {
+            to[i][j] = from[i][j];
+        }
+    }
+}
@@ -53,7 +53,7 @@ impl<S: Scalar> SquareMatrix<2, S> for Mat2<S> {
     const IDENTITY: Self = Matrix([
                                       [S::ONE, S::ZERO],
                                       [S::ZERO, S::ONE],
-                                  ], 4);
+                                  ]);
 
     fn identity_fill(value: S) -> Self {
         let mut output = Self::IDENTITY;
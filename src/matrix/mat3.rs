@@ -65,7 +65,7 @@ impl<S: Scalar> SquareMatrix<3, S> for Mat3<S> {
                                       [S::ONE, S::ZERO, S::ZERO],
                                       [S::ZERO, S::ONE, S::ZERO],
                                       [S::ZERO, S::ZERO, S::ONE]
-                                  ], 4);
+                                  ]);
 
     fn identity_fill(value: S) -> Self {
         crate::functions::mat_fill(value)
@@ -1,11 +1,9 @@
-use std::ffi::NulError;
 use std::ops::Mul;
 
 use crate::angle::Angle;
 use crate::matrix::Matrix;
 use crate::matrix::matrix_conversion::MatrixConversion;
 use crate::matrix::square_matrix::SquareMatrix;
-use crate::pointer::Pointer;
 use crate::scalar::Scalar;
 use crate::vector::Vector3;
 
@@ -33,6 +31,16 @@ impl<S: Scalar> Mat4<S> {
     pub fn translation(translation: Vector3<S>) -> Mat4<S> {
         crate::functions::translation(translation)
     }
+
+    ///Applies the matrix to a point (`w = 1`), dividing by the resulting `w` if it is not `1`.
+    pub fn transform_point(&self, point: Vector3<S>) -> Vector3<S> {
+        crate::functions::mat4_transform_point(*self, point)
+    }
+
+    ///Applies the matrix to a direction (`w = 0`), ignoring translation.
+    pub fn transform_vector(&self, vector: Vector3<S>) -> Vector3<S> {
+        crate::functions::mat4_transform_vector(*self, vector)
+    }
 }
 
 impl<S: Scalar> SquareMatrix<4, S> for Mat4<S> {
@@ -41,7 +49,7 @@ impl<S: Scalar> SquareMatrix<4, S> for Mat4<S> {
                                       [S::ZERO, S::ONE, S::ZERO, S::ZERO],
                                       [S::ZERO, S::ZERO, S::ONE, S::ZERO],
                                       [S::ZERO, S::ZERO, S::ZERO, S::ONE]
-                                  ], 4);
+                                  ]);
 
     fn identity_fill(value: S) -> Self {
         crate::functions::mat_identity_fill(value)
@@ -55,19 +63,3 @@ impl<S: Scalar> Mul<Self> for Mat4<S> {
         crate::functions::mat4_mul_mat4(self, rhs)
     }
 }
-
-#[cfg(feature = "gfx")]
-impl crate::gfx::GfxBind for Mat4<f32> {
-    fn bind(&self, name: &dyn ToString, program: u32) -> Result<(), NulError> {
-        unsafe {
-            let loc = self.uniform_location(name, program)?;
-            gl::UniformMatrix4fv(
-                loc,
-                1,
-                gl::FALSE,
-                self.as_ptr(),
-            );
-            Ok(())
-        }
-    }
-}
\ No newline at end of file
@@ -1,7 +1,9 @@
 use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-use crate::pointer::Pointer;
+use crate::pointer::{Plain, Pointer};
 use crate::scalar::Scalar;
+use crate::vector::Vector;
 
 pub mod f32;
 pub mod f64;
@@ -11,34 +13,218 @@ pub mod mat3;
 pub mod mat4;
 
 pub mod matrix_conversion;
+pub mod decomposition;
+pub mod eigen;
 
-#[derive(Debug, Clone, Copy)]
-pub struct Matrix<const M: usize, const N: usize, S: Scalar>(pub [[S; N]; M], pub usize);
+pub const DEFAULT_DECIMAL_PLACES: usize = 4;
+
+///A row-major M×N matrix. The layout is guaranteed to be exactly that of `[[S; N]; M]`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix<const M: usize, const N: usize, S: Scalar>(pub [[S; N]; M]);
 
 impl<const M: usize, const N: usize, S: Scalar> Matrix<M, N, S> {
-    pub const ZERO: Self = Matrix([[S::ZERO; N]; M], 4);
-    pub const ONE: Self = Matrix([[S::ONE; N]; M], 4);
+    pub const ZERO: Self = Matrix([[S::ZERO; N]; M]);
+    pub const ONE: Self = Matrix([[S::ONE; N]; M]);
 
     pub fn transpose(self) -> Matrix<N, M, S> {
         crate::functions::mat_transpose(self)
     }
 
-    pub fn set_decimal_places(&mut self, places: usize) {
-        self.1 = places;
+    ///The elements in row-major order.
+    pub fn as_slice(&self) -> &[S] {
+        self.0.as_flattened()
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        self.0.as_flattened_mut()
+    }
+
+    ///Wraps the matrix for printing with the given number of decimal places.
+    pub fn display(&self, places: usize) -> MatrixDisplay<'_, M, N, S> {
+        MatrixDisplay {
+            matrix: self,
+            places,
+        }
+    }
+
+    pub fn map<T: Scalar, F: FnMut(S) -> T>(self, mut f: F) -> Matrix<M, N, T> {
+        let mut out: Matrix<M, N, T> = Matrix::ZERO;
+        for i in 0..M {
+            for j in 0..N {
+                out.0[i][j] = f(self.0[i][j]);
+            }
+        }
+        out
+    }
+
+    pub fn zip_map<F: FnMut(S, S) -> S>(self, other: Self, mut f: F) -> Self {
+        let mut out = self;
+        for i in 0..M {
+            for j in 0..N {
+                out.0[i][j] = f(self.0[i][j], other.0[i][j]);
+            }
+        }
+        out
+    }
+
+    ///Element-wise product.
+    pub fn hadamard(self, other: Self) -> Self {
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    pub fn frobenius_norm(&self) -> S {
+        self.0.iter().flatten().map(|s| *s * *s).sum::<S>().square_root()
+    }
+
+    ///The element with the largest absolute value, returned as that absolute value.
+    pub fn max_abs(&self) -> S {
+        let mut max = S::ZERO;
+        for s in self.0.iter().flatten() {
+            if s.absolute() > max {
+                max = s.absolute();
+            }
+        }
+        max
+    }
+}
+
+impl<const M: usize, const N: usize, S: Scalar + Plain> Matrix<M, N, S> {
+    pub fn as_bytes(&self) -> &[u8] {
+        crate::pointer::bytes_of(self)
     }
 }
 
+///See [`Matrix::display`].
+pub struct MatrixDisplay<'a, const M: usize, const N: usize, S: Scalar> {
+    matrix: &'a Matrix<M, N, S>,
+    places: usize,
+}
+
+///Prints the matrix with [`DEFAULT_DECIMAL_PLACES`] (or the `{:.N}` precision, if given) in aligned columns between
+///bracket borders. `{:#}` prints a compact single-line form instead.
 impl<const M: usize, const N: usize, S: Scalar> Display for Matrix<M, N, S> {
-    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
-        let t = self.0.iter().map(|s| format!("{:?}", s)).collect::<Vec<String>>().join("\n");
-        _f.write_str(&t)
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let places = f.precision().unwrap_or(DEFAULT_DECIMAL_PLACES);
+        fmt_matrix(self, places, f)
     }
 }
 
+///The `{:.N}` precision, if given, still takes priority over the wrapped decimal places.
+impl<const M: usize, const N: usize, S: Scalar> Display for MatrixDisplay<'_, M, N, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let places = f.precision().unwrap_or(self.places);
+        fmt_matrix(self.matrix, places, f)
+    }
+}
+
+fn fmt_matrix<const M: usize, const N: usize, S: Scalar>(matrix: &Matrix<M, N, S>, places: usize, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let cells = matrix.0.map(|row| row.map(|s| s.format_places(places)));
+
+    if f.alternate() {
+        let rows = cells.iter().map(|row| format!("[{}]", row.join(", "))).collect::<Vec<String>>();
+        return write!(f, "[{}]", rows.join(", "));
+    }
+
+    let mut widths = [0; N];
+    for row in &cells {
+        for j in 0..N {
+            widths[j] = widths[j].max(row[j].chars().count());
+        }
+    }
+
+    for (i, row) in cells.iter().enumerate() {
+        let (open, close) = match (M, i) {
+            (1, _) => ('[', ']'),
+            (_, 0) => ('⎡', '⎤'),
+            (_, i) if i == M - 1 => ('⎣', '⎦'),
+            _ => ('⎢', '⎥'),
+        };
+        let row = (0..N).map(|j| format!("{:>1$}", row[j], widths[j])).collect::<Vec<String>>();
+        write!(f, "{} {} {}", open, row.join("  "), close)?;
+        if i != M - 1 {
+            writeln!(f)?;
+        }
+    }
+    Ok(())
+}
+
 impl<const M: usize, const N: usize, S: Scalar> Pointer for Matrix<M, N, S> {
     type Ptr = *const S;
 
     fn as_ptr(&self) -> Self::Ptr {
         &self.0[0][0] as *const S
     }
-}
\ No newline at end of file
+}
+
+impl<const M: usize, const N: usize, S: Scalar> Mul<Vector<N, S>> for Matrix<M, N, S> {
+    type Output = Vector<M, S>;
+
+    fn mul(self, rhs: Vector<N, S>) -> Self::Output {
+        crate::functions::mat_mul_vec(self, rhs)
+    }
+}
+
+impl<const M: usize, const N: usize, S: Scalar> Add<Self> for Matrix<M, N, S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_map(rhs, |a, b| a + b)
+    }
+}
+
+impl<const M: usize, const N: usize, S: Scalar> Sub<Self> for Matrix<M, N, S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_map(rhs, |a, b| a - b)
+    }
+}
+
+impl<const M: usize, const N: usize, S: Scalar> Neg for Matrix<M, N, S> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|a| -a)
+    }
+}
+
+impl<const M: usize, const N: usize, S: Scalar> Mul<S> for Matrix<M, N, S> {
+    type Output = Self;
+
+    fn mul(self, rhs: S) -> Self::Output {
+        self.map(|a| a * rhs)
+    }
+}
+
+impl<const M: usize, const N: usize, S: Scalar> Div<S> for Matrix<M, N, S> {
+    type Output = Self;
+
+    fn div(self, rhs: S) -> Self::Output {
+        self.map(|a| a / rhs)
+    }
+}
+
+impl<const M: usize, const N: usize, S: Scalar> AddAssign<Self> for Matrix<M, N, S> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const M: usize, const N: usize, S: Scalar> SubAssign<Self> for Matrix<M, N, S> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const M: usize, const N: usize, S: Scalar> MulAssign<S> for Matrix<M, N, S> {
+    fn mul_assign(&mut self, rhs: S) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const M: usize, const N: usize, S: Scalar> DivAssign<S> for Matrix<M, N, S> {
+    fn div_assign(&mut self, rhs: S) {
+        *self = *self / rhs;
+    }
+}
@@ -0,0 +1,225 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::angle::Angle;
+use crate::euler_angles::EulerAngles;
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::scalar::Scalar;
+use crate::vector::Vector;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    Empty,
+    InvalidNumber(String),
+    ///The input had `found` elements where `expected` were needed.
+    WrongLength { expected: usize, found: usize },
+    ///A bracket, separator or unit was missing or out of place.
+    Malformed(String),
+    UnknownUnit(String),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "empty input"),
+            ParseError::InvalidNumber(s) => write!(f, "invalid number `{}`", s),
+            ParseError::WrongLength { expected, found } => write!(f, "expected {} elements, found {}", expected, found),
+            ParseError::Malformed(s) => write!(f, "malformed input `{}`", s),
+            ParseError::UnknownUnit(s) => write!(f, "unknown angle unit `{}`", s),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+fn scalar<S: Scalar>(s: &str) -> Result<S, ParseError> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    s.parse().map_err(|_| ParseError::InvalidNumber(s.to_string()))
+}
+
+fn strip<'a>(s: &'a str, open: &[char], close: &[char]) -> Result<&'a str, ParseError> {
+    let t = s.trim();
+    t.strip_prefix(open)
+        .and_then(|t| t.strip_suffix(close))
+        .ok_or_else(|| ParseError::Malformed(s.to_string()))
+}
+
+fn array<T, const L: usize, F: Fn(&str) -> Result<T, ParseError>>(items: Vec<&str>, f: F) -> Result<[T; L], ParseError> {
+    if items.len() != L {
+        return Err(ParseError::WrongLength { expected: L, found: items.len() });
+    }
+    let parsed = items.into_iter().map(f).collect::<Result<Vec<T>, ParseError>>()?;
+    Ok(parsed.try_into().unwrap_or_else(|_| unreachable!()))
+}
+
+fn comma_separated(s: &str) -> Vec<&str> {
+    if s.trim().is_empty() {
+        Vec::new()
+    } else {
+        s.split(',').collect()
+    }
+}
+
+///Parses the `Display` form `[1, 2, 3]`.
+impl<const L: usize, S: Scalar> FromStr for Vector<L, S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let inner = strip(s, &['['], &[']'])?;
+        Ok(Vector(array(comma_separated(inner), scalar)?))
+    }
+}
+
+const LEFT_BRACKETS: [char; 4] = ['[', '⎡', '⎢', '⎣'];
+const RIGHT_BRACKETS: [char; 4] = [']', '⎤', '⎥', '⎦'];
+
+///Parses both `Display` forms: the bordered one with a line per row and whitespace separated columns, and the compact
+///`{:#}` one, `[[1, 2], [3, 4]]`. `Display` rounds to [`DEFAULT_DECIMAL_PLACES`](crate::matrix::DEFAULT_DECIMAL_PLACES)
+///unless given a precision, so parsing its output only gives back the matrix rounded to the printed places.
+impl<const M: usize, const N: usize, S: Scalar> FromStr for Matrix<M, N, S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let t = s.trim();
+        if t.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let rows: Vec<&str> = if t.starts_with("[[") {
+            let inner = strip(t, &['['], &[']'])?;
+            let mut rows = Vec::new();
+            let mut rest = inner.trim();
+            while !rest.is_empty() {
+                let end = rest.find(']').ok_or_else(|| ParseError::Malformed(s.to_string()))?;
+                rows.push(&rest[..=end]);
+                rest = rest[end + 1..].trim_start();
+                if let Some(r) = rest.strip_prefix(',') {
+                    rest = r.trim_start();
+                } else if !rest.is_empty() {
+                    return Err(ParseError::Malformed(s.to_string()));
+                }
+            }
+            rows
+        } else {
+            t.lines().collect()
+        };
+
+        let compact = t.starts_with("[[");
+        let rows: [[S; N]; M] = array(rows, |row| {
+            let inner = strip(row, &LEFT_BRACKETS, &RIGHT_BRACKETS)?;
+            if compact {
+                array(comma_separated(inner), scalar)
+            } else {
+                array(inner.split_whitespace().collect(), scalar)
+            }
+        })?;
+        Ok(Matrix(rows))
+    }
+}
+
+///Parses `w + xi + yj + zk` (terms in any order, missing terms are zero) and the `{:#}` form `(w, [x, y, z])`.
+///Like for matrices, parsing the `Display` output only gives back the quaternion rounded to the printed places.
+impl<S: Scalar> FromStr for Quaternion<S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let t = s.trim();
+        if t.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        if t.starts_with('(') {
+            let inner = strip(t, &['('], &[')'])?;
+            let (w, v) = inner.split_once(',').ok_or_else(|| ParseError::Malformed(s.to_string()))?;
+            return Ok(Quaternion::new(v.parse()?, scalar(w)?));
+        }
+
+        let compact: String = t.chars().filter(|c| !c.is_whitespace()).collect();
+        let mut terms = Vec::new();
+        let mut start = 0;
+        for (i, c) in compact.char_indices() {
+            let exponent = i > 0 && matches!(compact.as_bytes()[i - 1], b'e' | b'E');
+            if (c == '+' || c == '-') && i > start && !exponent {
+                terms.push(&compact[start..i]);
+                start = i;
+            }
+        }
+        terms.push(&compact[start..]);
+
+        let mut wxyz: [Option<S>; 4] = [None; 4];
+        for term in terms {
+            let (value, index) = match term.chars().last() {
+                Some('i') => (&term[..term.len() - 1], 1),
+                Some('j') => (&term[..term.len() - 1], 2),
+                Some('k') => (&term[..term.len() - 1], 3),
+                _ => (term, 0),
+            };
+            let value = value.strip_prefix('+').unwrap_or(value);
+            if wxyz[index].is_some() {
+                return Err(ParseError::Malformed(s.to_string()));
+            }
+            wxyz[index] = Some(scalar(value)?);
+        }
+
+        let [w, x, y, z] = wxyz.map(|c| c.unwrap_or(S::ZERO));
+        Ok(Quaternion::new(Vector([x, y, z]), w))
+    }
+}
+
+///Accepts `90°`, `90deg`, `1.57rad` and `0.25turn`; a bare number is in radians, matching `Display`.
+///Turns are converted to degrees. The number is parsed like any other scalar, so `inf` and `NaN` are accepted.
+impl<S: Scalar> FromStr for Angle<S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let t = s.trim();
+        if t.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        //Units are tried as suffixes first, so that numbers spelled with letters, like `inf`, are not taken for units
+        for unit in ["rad", "°", "deg", "turn", ""] {
+            let Some(value) = t.strip_suffix(unit) else { continue };
+            let Ok(value) = scalar::<S>(value) else { continue };
+            return Ok(match unit {
+                "" | "rad" => Angle::Radians(value),
+                "turn" => Angle::Degrees(value * S::from_f32(360.0)),
+                _ => Angle::Degrees(value),
+            });
+        }
+
+        //Either the number or the unit is wrong: the unit starts at the first letter that cannot be in a number
+        let split = t
+            .char_indices()
+            .find(|(_, c)| c.is_alphabetic() && *c != 'e' && *c != 'E' || *c == '°')
+            .map(|(i, _)| i)
+            .unwrap_or(t.len());
+        let (value, unit) = t.split_at(split);
+        if value.trim().is_empty() {
+            return Err(ParseError::InvalidNumber(t.to_string()));
+        }
+        scalar::<S>(value)?;
+        Err(ParseError::UnknownUnit(unit.trim().to_string()))
+    }
+}
+
+///Parses the `Display` form `[roll pitch yaw]`, e.g. `[90° 0.5 45deg]`.
+impl<S: Scalar> FromStr for EulerAngles<S> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let inner = strip(s, &['['], &[']'])?;
+        let [roll, pitch, yaw]: [Angle<S>; 3] = array(inner.split_whitespace().collect(), str::parse)?;
+        Ok(EulerAngles { roll, pitch, yaw })
+    }
+}
@@ -1,4 +1,82 @@
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::scalar::Scalar;
+use crate::vector::Vector;
+
 pub trait Pointer {
     type Ptr;
     fn as_ptr(&self) -> Self::Ptr;
-}
\ No newline at end of file
+}
+
+///Types that are nothing but initialized, padding-free scalars, so any value can be viewed as bytes.
+///
+///# Safety
+///Implementors must be `#[repr(C)]` (or primitive), contain no padding and have no invalid bit patterns.
+pub unsafe trait Plain: Copy + 'static {}
+
+macro_rules! plain {
+    ($($t:ty),*) => {
+        $(
+            unsafe impl Plain for $t {}
+        )*
+    };
+}
+
+plain!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+unsafe impl<const L: usize, S: Plain> Plain for Vector<L, S> {}
+unsafe impl<const M: usize, const N: usize, S: Scalar + Plain> Plain for Matrix<M, N, S> {}
+unsafe impl<S: Scalar + Plain> Plain for Quaternion<S> {}
+
+#[cfg(feature = "bytemuck")]
+mod pod {
+    use bytemuck::{Pod, Zeroable};
+
+    use crate::matrix::Matrix;
+    use crate::quaternion::Quaternion;
+    use crate::scalar::Scalar;
+    use crate::vector::Vector;
+
+    unsafe impl<const L: usize, S: Zeroable> Zeroable for Vector<L, S> {}
+    unsafe impl<const L: usize, S: Pod> Pod for Vector<L, S> {}
+
+    unsafe impl<const M: usize, const N: usize, S: Scalar + Zeroable> Zeroable for Matrix<M, N, S> {}
+    unsafe impl<const M: usize, const N: usize, S: Scalar + Pod> Pod for Matrix<M, N, S> {}
+
+    unsafe impl<S: Scalar + Zeroable> Zeroable for Quaternion<S> {}
+    unsafe impl<S: Scalar + Pod> Pod for Quaternion<S> {}
+}
+
+pub fn bytes_of<T: Plain>(value: &T) -> &[u8] {
+    slice_as_bytes(std::slice::from_ref(value))
+}
+
+pub fn slice_as_bytes<T: Plain>(values: &[T]) -> &[u8] {
+    unsafe { std::slice::from_raw_parts(values.as_ptr() as *const u8, std::mem::size_of_val(values)) }
+}
+
+///Views a slice of vectors as one flat slice of their components.
+pub fn flatten<const L: usize, S>(vectors: &[Vector<L, S>]) -> &[S] {
+    //Vector is repr(C) over [S; L]
+    unsafe { std::slice::from_raw_parts(vectors.as_ptr() as *const S, vectors.len() * L) }
+}
+
+pub fn flatten_mut<const L: usize, S>(vectors: &mut [Vector<L, S>]) -> &mut [S] {
+    unsafe { std::slice::from_raw_parts_mut(vectors.as_mut_ptr() as *mut S, vectors.len() * L) }
+}
+
+///Views a slice of matrices as one flat, row-major slice of their elements.
+pub fn flatten_matrices<const M: usize, const N: usize, S: Scalar>(matrices: &[Matrix<M, N, S>]) -> &[S] {
+    //Matrix is repr(C) over [[S; N]; M]
+    unsafe { std::slice::from_raw_parts(matrices.as_ptr() as *const S, matrices.len() * M * N) }
+}
+
+pub fn flatten_matrices_mut<const M: usize, const N: usize, S: Scalar>(matrices: &mut [Matrix<M, N, S>]) -> &mut [S] {
+    unsafe { std::slice::from_raw_parts_mut(matrices.as_mut_ptr() as *mut S, matrices.len() * M * N) }
+}
+
+///Views a slice of quaternions as one flat slice of `[x, y, z, w]` components.
+pub fn flatten_quaternions<S: Scalar>(quaternions: &[Quaternion<S>]) -> &[S] {
+    //Quaternion is repr(C) over a Vector3 and a scalar
+    unsafe { std::slice::from_raw_parts(quaternions.as_ptr() as *const S, quaternions.len() * 4) }
+}
@@ -0,0 +1,14 @@
+//!Everything needed to port GLSL code: `use math::prelude::*;` brings in the GLSL constructors and shader functions,
+//!the GLSL type names (`vec3`, `mat4`, `float`, ...) and the crate's core types.
+
+pub use crate::angle::Angle;
+pub use crate::functions::{
+    clamp, cos, faceforward, fract, inversesqrt, length, mat2, mat3, mat4, mix, r#mod, sign, sin, smoothstep, sqrt,
+    step, tan, vec2, vec3, vec4, GenType,
+};
+pub use crate::matrix::Matrix;
+pub use crate::matrix::square_matrix::SquareMatrix;
+pub use crate::quaternion::Quaternion;
+pub use crate::scalar::Scalar;
+pub use crate::types::*;
+pub use crate::vector::{Vector, Vector2, Vector3, Vector4};
@@ -1,3 +1,4 @@
+use std::fmt::{Display, Formatter};
 use std::ops::{Div, Mul};
 
 use crate::angle::Angle;
@@ -5,10 +6,13 @@ use crate::euler_angles::EulerAngles;
 use crate::functions::{atan2, cos, sin, sqrt, vec3};
 use crate::matrix::mat3::Mat3;
 use crate::matrix::Matrix;
+use crate::pointer::{Plain, Pointer};
 use crate::scalar::Scalar;
 use crate::vector::Vector3;
 
-#[derive(Debug, Clone, Copy)]
+///Laid out as `[x, y, z, w]`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Quaternion<S: Scalar> {
     xyz: Vector3<S>,
     w: S,
@@ -94,17 +98,52 @@ impl<S: Scalar> Quaternion<S> {
     pub fn rotation_matrix(self) -> Mat3<S> {
         self.into()
     }
+
+    ///A unit quaternion rotating by `angle` around `axis`.
+    pub fn from_axis_angle(axis: Vector3<S>, angle: Angle<S>) -> Self {
+        let half = angle.to_radians().to_inner() / S::from_f32(2.0);
+        let (sin, cos) = half.sine_cosine();
+        Self::new(axis.normalized() * sin, cos)
+    }
+
+    ///Rotates `v` by this (unit) quaternion.
+    pub fn rotate(&self, v: Vector3<S>) -> Vector3<S> {
+        crate::functions::quat_rotate_vec3(*self, v)
+    }
+
+    ///The components as `[x, y, z, w]`.
+    pub fn as_slice(&self) -> &[S] {
+        unsafe { std::slice::from_raw_parts(self.as_ptr(), 4) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut S, 4) }
+    }
 }
 
-impl<S: Scalar> Into<Mat3<S>> for Quaternion<S> {
-    fn into(self) -> Mat3<S> {
-        let (w, x, y, z) = self.w_xyz();
+impl<S: Scalar + Plain> Quaternion<S> {
+    pub fn as_bytes(&self) -> &[u8] {
+        crate::pointer::bytes_of(self)
+    }
+}
+
+impl<S: Scalar> Pointer for Quaternion<S> {
+    type Ptr = *const S;
+
+    fn as_ptr(&self) -> Self::Ptr {
+        self as *const Self as *const S
+    }
+}
+
+impl<S: Scalar> From<Quaternion<S>> for Mat3<S> {
+    fn from(value: Quaternion<S>) -> Self {
+        let (w, x, y, z) = value.w_xyz();
         let (one, two): (S, S) = (S::ONE, S::from_f32(2.0));
         Matrix([
                    [one - two * y * y - two * z * z, two * x * y - two * w * z, two * x * z + two * w * y],
                    [two * x * y + two * w * z, one - two * x * x - two * z * z, two * y * z - two * w * x],
                    [two * x * z - two * w * y, two * y * z + two * w * x, one - two * x * x - two * y * y],
-               ], 4)
+               ])
     }
 }
 
@@ -147,16 +186,16 @@ impl<S: Scalar> From<EulerAngles<S>> for Quaternion<S> {
     }
 }
 
-impl<S: Scalar> Into<EulerAngles<S>> for Quaternion<S> {
-    fn into(self) -> EulerAngles<S> {
+impl<S: Scalar> From<Quaternion<S>> for EulerAngles<S> {
+    fn from(value: Quaternion<S>) -> Self {
         let one = S::ONE;
         let two = S::from_f32(2.0);
         let pi = S::PI;
 
-        let q_w = self.w;
-        let q_x = *self.x();
-        let q_y = *self.y();
-        let q_z = *self.z();
+        let q_w = value.w;
+        let q_x = *value.x();
+        let q_y = *value.y();
+        let q_z = *value.z();
 
         let sinr_cosp = two * (
             q_w * q_x + q_y * q_z
@@ -200,4 +239,24 @@ impl<S: Scalar> Div<S> for Quaternion<S> {
             w: self.w / rhs,
         }
     }
-}
\ No newline at end of file
+}
+///Prints <i>w + xi + yj + zk</i> with four decimal places (or the `{:.N}` precision, if given).
+///`{:#}` prints the scalar-vector form <i>(s, [v])</i> instead.
+impl<S: Scalar> Display for Quaternion<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let places = f.precision().unwrap_or(4);
+        let (w, x, y, z) = self.w_xyz();
+
+        if f.alternate() {
+            return write!(f, "({}, [{}, {}, {}])",
+                          w.format_places(places), x.format_places(places), y.format_places(places), z.format_places(places));
+        }
+
+        write!(f, "{}", w.format_places(places))?;
+        for (s, unit) in [(x, 'i'), (y, 'j'), (z, 'k')] {
+            let sign = if s < S::ZERO { '-' } else { '+' };
+            write!(f, " {} {}{}", sign, s.absolute().format_places(places), unit)?;
+        }
+        Ok(())
+    }
+}
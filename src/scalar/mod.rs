@@ -1,6 +1,7 @@
 use std::fmt::{Debug, Display};
 use std::iter::{Product, Sum};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
 use crate::angle::Angle;
 
@@ -8,7 +9,8 @@ crate::scalar!(f32, f64);
 
 pub trait Scalar:
 Clone + Copy +
-Debug + Display +
+Debug + Display + FromStr +
+PartialOrd +
 Sum + Product +
 Neg<Output=Self> +
 Add<Output=Self> + Sub<Output=Self> + Mul<Output=Self> + Div<Output=Self> +
@@ -18,12 +20,16 @@ AddAssign + SubAssign + MulAssign + DivAssign {
     const NEG_ONE: Self;
 
     const PI: Self;
+    const EPSILON: Self;
+    const INFINITY: Self;
 
     fn rad(self) -> Self;
     fn deg(self) -> Self;
 
     fn pow(&self, f: Self) -> Self;
     fn square_root(&self) -> Self;
+    fn absolute(self) -> Self;
+    fn floored(self) -> Self;
 
     fn angle_rad(self) -> Angle<Self>;
     fn angle_deg(self) -> Angle<Self>;
@@ -45,6 +51,7 @@ AddAssign + SubAssign + MulAssign + DivAssign {
     }
 
     fn inv_tangent2(self, b: Self) -> Self;
+    fn inv_cosine(self) -> Self;
 
     fn from_f32(f: f32) -> Self;
 
@@ -60,6 +67,8 @@ macro_rules! scalar {
                 const ONE: Self = 1.0_f64 as Self;
                 const NEG_ONE: Self = -1.0_f64 as Self;
                 const PI: Self = std::f64::consts::PI as Self;
+                const EPSILON: Self = <$s>::EPSILON;
+                const INFINITY: Self = <$s>::INFINITY;
 
                 fn rad(self) -> Self {
                     self.to_radians()
@@ -74,6 +83,12 @@ macro_rules! scalar {
                 fn square_root(&self) -> Self {
                     self.sqrt()
                 }
+                fn absolute(self) -> Self {
+                    self.abs()
+                }
+                fn floored(self) -> Self {
+                    self.floor()
+                }
                 fn angle_rad(self) -> Angle<Self> {
                     Angle::Radians(self)
                 }
@@ -93,6 +108,9 @@ macro_rules! scalar {
                 fn inv_tangent2(self, b: Self) -> Self {
                     self.atan2(b)
                 }
+                fn inv_cosine(self) -> Self {
+                    self.acos()
+                }
 
                 fn from_f32(f: f32) -> Self {
                     f as Self
@@ -0,0 +1,86 @@
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+
+use serde::de::{Error, IgnoredAny, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::scalar::Scalar;
+use crate::vector::Vector;
+
+fn serialize_array<T: Serialize, Se: Serializer>(values: &[T], serializer: Se) -> Result<Se::Ok, Se::Error> {
+    let mut tuple = serializer.serialize_tuple(values.len())?;
+    for v in values {
+        tuple.serialize_element(v)?;
+    }
+    tuple.end()
+}
+
+///Reads a sequence of exactly `L` elements.
+struct ArrayVisitor<const L: usize, T>(PhantomData<T>);
+
+impl<'de, const L: usize, T: Deserialize<'de>> Visitor<'de> for ArrayVisitor<L, T> {
+    type Value = [T; L];
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "an array of {} elements", L)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut out = Vec::with_capacity(L);
+        for i in 0..L {
+            out.push(seq.next_element()?.ok_or_else(|| A::Error::invalid_length(i, &self))?);
+        }
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(A::Error::invalid_length(L + 1, &self));
+        }
+        Ok(out.try_into().unwrap_or_else(|_| unreachable!()))
+    }
+}
+
+fn deserialize_array<'de, const L: usize, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<[T; L], D::Error> {
+    deserializer.deserialize_tuple(L, ArrayVisitor::<L, T>(PhantomData))
+}
+
+///`[x, y, z]`
+impl<const L: usize, S: Serialize> Serialize for Vector<L, S> {
+    fn serialize<Se: Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+        serialize_array(&self.0, serializer)
+    }
+}
+
+impl<'de, const L: usize, S: Deserialize<'de>> Deserialize<'de> for Vector<L, S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vector(deserialize_array(deserializer)?))
+    }
+}
+
+///`[[a, b], [c, d]]`, one inner array per row.
+impl<const M: usize, const N: usize, S: Scalar + Serialize> Serialize for Matrix<M, N, S> {
+    fn serialize<Se: Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+        serialize_array(&self.0.map(Vector), serializer)
+    }
+}
+
+impl<'de, const M: usize, const N: usize, S: Scalar + Deserialize<'de>> Deserialize<'de> for Matrix<M, N, S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows: [Vector<N, S>; M] = deserialize_array(deserializer)?;
+        Ok(Matrix(rows.map(|r| r.0)))
+    }
+}
+
+///`[x, y, z, w]`
+impl<S: Scalar + Serialize> Serialize for Quaternion<S> {
+    fn serialize<Se: Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+        serialize_array(self.as_slice(), serializer)
+    }
+}
+
+impl<'de, S: Scalar + Deserialize<'de>> Deserialize<'de> for Quaternion<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let [x, y, z, w]: [S; 4] = deserialize_array(deserializer)?;
+        Ok(Quaternion::new(Vector([x, y, z]), w))
+    }
+}
@@ -13,6 +13,28 @@ crate::number_types!(
     f64 => double
 );
 
+crate::number_types!(
+    crate::vector::Vector2<f32> => vec2,
+    crate::vector::Vector3<f32> => vec3,
+    crate::vector::Vector4<f32> => vec4,
+    crate::vector::Vector2<f64> => dvec2,
+    crate::vector::Vector3<f64> => dvec3,
+    crate::vector::Vector4<f64> => dvec4,
+    crate::vector::Vector2<i32> => ivec2,
+    crate::vector::Vector3<i32> => ivec3,
+    crate::vector::Vector4<i32> => ivec4,
+    crate::vector::Vector2<u32> => uvec2,
+    crate::vector::Vector3<u32> => uvec3,
+    crate::vector::Vector4<u32> => uvec4,
+
+    crate::matrix::mat2::Mat2<f32> => mat2,
+    crate::matrix::mat3::Mat3<f32> => mat3,
+    crate::matrix::mat4::Mat4<f32> => mat4,
+    crate::matrix::mat2::Mat2<f64> => dmat2,
+    crate::matrix::mat3::Mat3<f64> => dmat3,
+    crate::matrix::mat4::Mat4<f64> => dmat4
+);
+
 #[macro_export]
 macro_rules! number_types {
     ($($t:ty=>$i:ident),*) => {
@@ -1,8 +1,8 @@
 use std::fmt::{Display, Formatter};
 use std::mem::size_of;
-use std::ops::{Div, Neg, Sub};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-use crate::pointer::Pointer;
+use crate::pointer::{Plain, Pointer};
 use crate::scalar::Scalar;
 
 pub mod f32;
@@ -12,10 +12,13 @@ pub type Vector2<S> = Vector<2, S>;
 pub type Vector3<S> = Vector<3, S>;
 pub type Vector4<S> = Vector<4, S>;
 
-#[derive(Debug, Clone, Copy)]
-pub struct Vector<const L: usize, S: Scalar>(pub [S; L]);
+///Vectors are usually built over a [`Scalar`], but the component type is left unbounded so that integer vectors
+///(e.g. `Vector<3, i32>`) can be handed to the graphics layer. The layout is guaranteed to be exactly that of `[S; L]`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vector<const L: usize, S>(pub [S; L]);
 
-impl<const L: usize, S: Scalar> Pointer for Vector<L, S> {
+impl<const L: usize, S> Pointer for Vector<L, S> {
     type Ptr = *const S;
 
     fn as_ptr(&self) -> Self::Ptr {
@@ -23,6 +26,22 @@ impl<const L: usize, S: Scalar> Pointer for Vector<L, S> {
     }
 }
 
+impl<const L: usize, S> Vector<L, S> {
+    pub fn as_slice(&self) -> &[S] {
+        &self.0
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [S] {
+        &mut self.0
+    }
+}
+
+impl<const L: usize, S: Plain> Vector<L, S> {
+    pub fn as_bytes(&self) -> &[u8] {
+        crate::pointer::bytes_of(self)
+    }
+}
+
 impl<const L: usize, S: Scalar> Display for Vector<L, S> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let s = self.0.iter().map(|s| s.to_string()).collect::<Vec<String>>().join(", ");
@@ -46,12 +65,58 @@ impl<const L: usize, S: Scalar> Vector<L, S> {
     pub fn normalize(&mut self) -> Self {
         let m = self.magnitude();
         self.0.iter_mut().for_each(|s| *s /= m);
-        self.clone()
+        *self
     }
 
     pub fn normalized(&self) -> Self {
         self.clone().normalize()
     }
+
+    pub fn magnitude_squared(&self) -> S {
+        self.dot_product(*self)
+    }
+
+    pub fn distance(&self, b: Self) -> S {
+        (*self - b).magnitude()
+    }
+
+    pub fn distance_squared(&self, b: Self) -> S {
+        (*self - b).magnitude_squared()
+    }
+
+    ///Component-wise minimum.
+    pub fn min(&self, b: Self) -> Self {
+        let mut out = *self;
+        for i in 0..L {
+            if b.0[i] < out.0[i] {
+                out.0[i] = b.0[i];
+            }
+        }
+        out
+    }
+
+    ///Component-wise maximum.
+    pub fn max(&self, b: Self) -> Self {
+        let mut out = *self;
+        for i in 0..L {
+            if b.0[i] > out.0[i] {
+                out.0[i] = b.0[i];
+            }
+        }
+        out
+    }
+
+    ///Linear interpolation, `self` at `t = 0` and `b` at `t = 1`.
+    pub fn lerp(&self, b: Self, t: S) -> Self {
+        *self + (b - *self) * t
+    }
+}
+
+///Fills every component with the scalar, like GLSL's `vec3(1.0)`.
+impl<const L: usize, S: Scalar> From<S> for Vector<L, S> {
+    fn from(value: S) -> Self {
+        Self([value; L])
+    }
 }
 
 impl<const L: usize, S: Scalar> Neg for Vector<L, S> {
@@ -59,9 +124,33 @@ impl<const L: usize, S: Scalar> Neg for Vector<L, S> {
 
     fn neg(self) -> Self::Output {
         let neg_one = -S::ONE;
-        let mut o = self.0.clone();
+        let mut o = self.0;
+        for j in &mut o {
+            *j *= neg_one;
+        }
+        Self(o)
+    }
+}
+
+impl<const L: usize, S: Scalar> Add<Self> for Vector<L, S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut out = self;
         for i in 0..L {
-            o[i] *= neg_one;
+            out.0[i] += rhs.0[i];
+        }
+        out
+    }
+}
+
+impl<const L: usize, S: Scalar> Mul<S> for Vector<L, S> {
+    type Output = Self;
+
+    fn mul(self, rhs: S) -> Self::Output {
+        let mut o = self.0;
+        for j in &mut o {
+            *j *= rhs;
         }
         Self(o)
     }
@@ -71,7 +160,7 @@ impl<const L: usize, S: Scalar> Div<S> for Vector<L, S> {
     type Output = Self;
 
     fn div(self, rhs: S) -> Self::Output {
-        let mut o = self.0.clone();
+        let mut o = self.0;
         for j in &mut o {
             *j /= rhs;
         }
@@ -83,7 +172,7 @@ impl<const L: usize, S: Scalar> Sub<Self> for Vector<L, S> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let mut out = self.clone();
+        let mut out = self;
         for i in 0..L {
             out.0[i] -= rhs.0[i];
         }
@@ -91,6 +180,30 @@ impl<const L: usize, S: Scalar> Sub<Self> for Vector<L, S> {
     }
 }
 
+impl<const L: usize, S: Scalar> AddAssign<Self> for Vector<L, S> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const L: usize, S: Scalar> SubAssign<Self> for Vector<L, S> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const L: usize, S: Scalar> MulAssign<S> for Vector<L, S> {
+    fn mul_assign(&mut self, rhs: S) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const L: usize, S: Scalar> DivAssign<S> for Vector<L, S> {
+    fn div_assign(&mut self, rhs: S) {
+        *self = *self / rhs;
+    }
+}
+
 impl<S: Scalar> Vector2<S> {
     pub const UP: Self = Vector([S::ZERO, S::ONE]);
     pub const DOWN: Self = Vector([S::ZERO, S::NEG_ONE]);
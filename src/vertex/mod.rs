@@ -0,0 +1,122 @@
+use crate::vector::Vector;
+
+///GL type enums, duplicated here so vertex layouts can be described without the `gfx` feature.
+pub const GL_INT: u32 = 0x1404;
+pub const GL_UNSIGNED_INT: u32 = 0x1405;
+pub const GL_FLOAT: u32 = 0x1406;
+pub const GL_DOUBLE: u32 = 0x140A;
+
+///A type that can be fed to a vertex attribute.
+pub trait AttributeType {
+    const COMPONENTS: usize;
+    const GL_TYPE: u32;
+}
+
+macro_rules! attribute_type {
+    ($($s:ty => $gl:ident),*) => {
+        $(
+            impl AttributeType for $s {
+                const COMPONENTS: usize = 1;
+                const GL_TYPE: u32 = $gl;
+            }
+
+            impl<const L: usize> AttributeType for Vector<L, $s> {
+                const COMPONENTS: usize = L;
+                const GL_TYPE: u32 = $gl;
+            }
+        )*
+    };
+}
+
+attribute_type!(
+    f32 => GL_FLOAT,
+    f64 => GL_DOUBLE,
+    i32 => GL_INT,
+    u32 => GL_UNSIGNED_INT
+);
+
+///One `glVertexAttribPointer` call worth of information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexAttribute {
+    pub location: u32,
+    pub components: usize,
+    pub gl_type: u32,
+    pub normalized: bool,
+    pub offset: usize,
+    pub stride: usize,
+}
+
+impl VertexAttribute {
+    pub fn new<T: AttributeType>(location: u32, offset: usize, stride: usize) -> Self {
+        Self {
+            location,
+            components: T::COMPONENTS,
+            gl_type: T::GL_TYPE,
+            normalized: false,
+            offset,
+            stride,
+        }
+    }
+
+    ///Same as [`VertexAttribute::new`], with the attribute type taken from a field accessor, e.g. `|v: &V| &v.position`.
+    pub fn of_field<V, T: AttributeType>(location: u32, offset: usize, _field: fn(&V) -> &T) -> Self {
+        Self::new::<T>(location, offset, std::mem::size_of::<V>())
+    }
+
+    ///Integer attributes marked normalized are converted to floats in `[0, 1]` (or `[-1, 1]` if signed).
+    pub fn normalized(mut self) -> Self {
+        self.normalized = true;
+        self
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.gl_type == GL_INT || self.gl_type == GL_UNSIGNED_INT
+    }
+}
+
+///Describes the attributes of an interleaved vertex struct. Usually implemented with [`vertex_layout!`](crate::vertex_layout).
+pub trait Vertex: Sized {
+    fn attributes() -> Vec<VertexAttribute>;
+
+    fn stride() -> usize {
+        std::mem::size_of::<Self>()
+    }
+}
+
+///Implements [`Vertex`] for a `#[repr(C)]` struct, mapping fields to attribute locations.
+///Follow a location with `normalized` to normalize integer data.
+///
+///```
+///use math::vector::{Vector2, Vector3};
+///
+///#[repr(C)]
+///struct Textured {
+///    position: Vector3<f32>,
+///    color: math::vector::Vector<4, u32>,
+///    uv: Vector2<f32>,
+///}
+///
+///math::vertex_layout!(Textured {
+///    position: 0,
+///    color: 1 normalized,
+///    uv: 2,
+///});
+///```
+#[macro_export]
+macro_rules! vertex_layout {
+    ($v:ty { $($field:ident : $location:literal $($normalized:ident)?),* $(,)? }) => {
+        impl $crate::vertex::Vertex for $v {
+            fn attributes() -> Vec<$crate::vertex::VertexAttribute> {
+                vec![
+                    $(
+                        $crate::vertex::VertexAttribute::of_field(
+                            $location,
+                            std::mem::offset_of!($v, $field),
+                            |v: &$v| &v.$field,
+                        )$(.$normalized())?
+                    ),*
+                ]
+            }
+        }
+    };
+}